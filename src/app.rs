@@ -1,7 +1,9 @@
 use crate::{
-    AppInitializationService, Config, ConfigProvider, DefaultConfigProvider, DefaultExitHandler,
-    DefaultMessageHandler, ExitHandler, MessageHandler, runtime::run_polling_loop_with_shutdown,
+    AppInitializationService, AuthCommands, Cli, Commands, Config, ConfigCommands, ConfigProvider,
+    DebugCommands, DefaultConfigProvider, DefaultExitHandler, DefaultMessageHandler, ExitHandler,
+    FiltersCommands, MessageHandler, runtime::run_polling_loop_with_shutdown,
 };
+use clap::Parser;
 
 /// Main application structure
 pub struct Application;
@@ -9,16 +11,493 @@ pub struct Application;
 impl Application {
     /// Run the GitHub Notifier application with default implementations
     pub async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        Self::run_with_deps(
-            &DefaultConfigProvider,
-            &DefaultExitHandler,
-            &DefaultMessageHandler,
-        )
-        .await
+        let cli = Cli::parse();
+
+        // Select the profile before anything resolves config/state/token paths
+        crate::paths::set_profile(cli.profile)?;
+        crate::paths::set_config_path(cli.config)?;
+
+        match cli.command {
+            Some(Commands::Doctor) => {
+                Self::run_doctor(
+                    &DefaultConfigProvider,
+                    &crate::doctor::DefaultDoctorEnvironment,
+                    &DefaultExitHandler,
+                    &DefaultMessageHandler,
+                )
+                .await
+            }
+            Some(Commands::Stats {
+                cumulative,
+                format,
+                top,
+            }) => Self::run_stats(cumulative, format, top, &DefaultMessageHandler),
+            Some(Commands::Config {
+                command: ConfigCommands::Validate,
+            }) => Self::run_config_validate(
+                &DefaultConfigProvider,
+                &DefaultExitHandler,
+                &DefaultMessageHandler,
+            ),
+            Some(Commands::Auth {
+                command: AuthCommands::Login { keychain },
+            }) => {
+                Self::run_auth_login(
+                    keychain,
+                    &DefaultConfigProvider,
+                    &DefaultExitHandler,
+                    &DefaultMessageHandler,
+                )
+                .await
+            }
+            Some(Commands::Auth {
+                command: AuthCommands::Status,
+            }) => Self::run_auth_status(
+                &DefaultConfigProvider,
+                &DefaultExitHandler,
+                &DefaultMessageHandler,
+            ),
+            Some(Commands::Auth {
+                command: AuthCommands::Logout,
+            }) => Self::run_auth_logout(&DefaultConfigProvider, &DefaultMessageHandler),
+            Some(Commands::MarkRead {
+                older_than,
+                repository,
+            }) => {
+                Self::run_mark_read(
+                    &older_than,
+                    repository.as_deref(),
+                    &DefaultConfigProvider,
+                    &DefaultExitHandler,
+                    &DefaultMessageHandler,
+                )
+                .await
+            }
+            Some(Commands::Filters {
+                command: FiltersCommands::FromGithub,
+            }) => {
+                Self::run_filters_from_github(
+                    &DefaultConfigProvider,
+                    &DefaultExitHandler,
+                    &DefaultMessageHandler,
+                )
+                .await
+            }
+            Some(Commands::Debug {
+                command: DebugCommands::ShowRaw { id },
+            }) => Self::run_show_raw(&id, &DefaultExitHandler, &DefaultMessageHandler),
+            Some(Commands::Debug {
+                command:
+                    DebugCommands::Bundle {
+                        output,
+                        include_data,
+                    },
+            }) => Self::run_debug_bundle(
+                &DefaultConfigProvider,
+                &output,
+                include_data,
+                &DefaultExitHandler,
+                &DefaultMessageHandler,
+            ),
+            None => {
+                Self::run_with_deps(
+                    cli.dry_run,
+                    &DefaultConfigProvider,
+                    &DefaultExitHandler,
+                    &DefaultMessageHandler,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Run the diagnostic checks and exit non-zero if a critical check failed
+    pub async fn run_doctor(
+        config_provider: &dyn ConfigProvider,
+        environment: &dyn crate::doctor::DoctorEnvironment,
+        exit_handler: &dyn ExitHandler,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let results = crate::doctor::run_checks(config_provider, environment).await;
+        crate::doctor::print_report(&results, message_handler);
+
+        if crate::doctor::has_critical_failure(&results) {
+            exit_handler.exit(1);
+        }
+        Ok(())
+    }
+
+    /// Load and validate the config file, print warnings and the parsed settings
+    /// (with the token masked), and exit non-zero if the config is invalid
+    pub fn run_config_validate(
+        config_provider: &dyn ConfigProvider,
+        exit_handler: &dyn ExitHandler,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config_path = crate::paths::config_file_path();
+        let config = match config_provider.load_config() {
+            Ok(config) => config,
+            Err(e) => {
+                message_handler.eprint(&format!(
+                    "error: {} is invalid: {}",
+                    config_path.display(),
+                    e
+                ));
+                exit_handler.exit(1);
+                return Ok(());
+            }
+        };
+
+        if !config_path.exists() {
+            message_handler.print(&format!(
+                "{} does not exist, the default settings are used",
+                config_path.display()
+            ));
+        }
+        for warning in crate::config::config_warnings(&config) {
+            message_handler.print(&format!("warning: {}", warning));
+        }
+
+        message_handler.print(&format!("{} is valid", config_path.display()));
+        message_handler.print("");
+        message_handler.print(&crate::debug_bundle::masked_config(&config)?);
+        Ok(())
+    }
+
+    /// Ask for a new PAT, verify it against `/user` and store it in the config file
+    /// (or the OS keychain / token file with `keychain`)
+    ///
+    /// Only the `pat` line is replaced, so the state file and other settings are kept
+    pub async fn run_auth_login(
+        keychain: bool,
+        config_provider: &dyn ConfigProvider,
+        exit_handler: &dyn ExitHandler,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config = config_provider.load_config().map_err(|e| e.to_string())?;
+
+        message_handler.print(
+            "Create a PAT at https://github.com/settings/tokens with the 'notifications' scope.",
+        );
+        let pat = rpassword::prompt_password("GitHub Personal Access Token: ")?;
+        let pat = pat.trim();
+        if pat.is_empty() {
+            message_handler.eprint("No PAT entered.");
+            exit_handler.exit(1);
+            return Ok(());
+        }
+
+        let mut auth_manager = crate::AuthManager::new()?;
+        auth_manager.set_github_config(config.github.clone());
+        auth_manager.token_info = Some(crate::TokenInfo::from_pat(pat));
+        match auth_manager.validate_token().await {
+            Ok(true) => {}
+            Ok(false) => {
+                message_handler.eprint("GitHub rejected the PAT. The config file was not changed.");
+                exit_handler.exit(1);
+                return Ok(());
+            }
+            Err(e) => {
+                message_handler.eprint(&format!(
+                    "Failed to verify the PAT: {}. The config file was not changed.",
+                    e
+                ));
+                exit_handler.exit(1);
+                return Ok(());
+            }
+        }
+
+        if keychain {
+            auth_manager.save_token_to_storage(&crate::TokenInfo::from_pat(pat))?;
+            message_handler
+                .print("PAT verified and saved to the keychain. Restart gh-notifier to use it.");
+            if crate::initialization_service::configured_pat(&config).is_some() {
+                message_handler.print(
+                    "Note: the `pat` in the config file takes precedence. Remove it to use the saved PAT.",
+                );
+            }
+            return Ok(());
+        }
+
+        crate::config::save_pat(pat).map_err(|e| e.to_string())?;
+        message_handler.print(&format!(
+            "PAT verified and saved to {}. Restart gh-notifier to use it.",
+            crate::paths::config_file_path().display()
+        ));
+        Ok(())
+    }
+
+    /// Print whether a PAT is available and where it comes from (the token itself is never printed)
+    ///
+    /// Exits non-zero when no PAT is available
+    pub fn run_auth_status(
+        config_provider: &dyn ConfigProvider,
+        exit_handler: &dyn ExitHandler,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config = config_provider.load_config().map_err(|e| e.to_string())?;
+        let source = token_source(
+            crate::initialization_service::configured_pat(&config).is_some(),
+            crate::initialization_service::stored_pat().is_some(),
+        );
+        match source {
+            TokenSource::Config => message_handler.print(&format!(
+                "PAT: set in the config file ({})",
+                crate::paths::config_file_path().display()
+            )),
+            TokenSource::Keychain => {
+                message_handler.print("PAT: saved in the keychain (auth login --keychain)")
+            }
+            TokenSource::None => {
+                message_handler.print("PAT: not set. Run `gh-notifier auth login` to set one.");
+                exit_handler.exit(1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Delete the PAT saved in the keychain / token file
+    pub fn run_auth_logout(
+        config_provider: &dyn ConfigProvider,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config = config_provider.load_config().map_err(|e| e.to_string())?;
+        let mut auth_manager = crate::AuthManager::new()?;
+        auth_manager.delete_token_from_storage()?;
+        message_handler.print("Deleted the PAT saved in the keychain.");
+        if crate::initialization_service::configured_pat(&config).is_some() {
+            message_handler.print(&format!(
+                "The `pat` in {} is still set. Remove it to sign out completely.",
+                crate::paths::config_file_path().display()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Print a suggested filter config based on the watched repositories
+    pub async fn run_filters_from_github(
+        config_provider: &dyn ConfigProvider,
+        exit_handler: &dyn ExitHandler,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config = config_provider.load_config().map_err(|e| e.to_string())?;
+        let Some(pat) = crate::initialization_service::configured_pat(&config)
+            .or_else(crate::initialization_service::stored_pat)
+        else {
+            message_handler.eprint(
+                "No PAT found. Run `gh-notifier auth login` or add 'pat = \"your_token_here\"' to your config file.",
+            );
+            exit_handler.exit(1);
+            return Ok(());
+        };
+
+        let mut auth_manager = crate::AuthManager::new()?;
+        auth_manager.token_info = Some(crate::TokenInfo::from_pat(&pat));
+        auth_manager.set_github_config(config.github.clone());
+        let mut github_client = crate::GitHubClient::with_config(auth_manager, &config.github)?;
+
+        match github_client.get_watched_repositories().await {
+            Ok(repositories) => {
+                message_handler.print(&crate::filter_suggestion::format_suggestion(&repositories))
+            }
+            Err(e) => {
+                message_handler.eprint(&format!("Failed to get watched repositories: {}", e));
+                exit_handler.exit(1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Mark every unread notification (optionally of one repository) last updated
+    /// longer ago than `older_than` as read, and print how many were marked
+    pub async fn run_mark_read(
+        older_than: &str,
+        repository: Option<&str>,
+        config_provider: &dyn ConfigProvider,
+        exit_handler: &dyn ExitHandler,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let older_than = match crate::polling::utils::parse_duration(older_than) {
+            Ok(duration) if !duration.is_zero() => duration,
+            Ok(_) | Err(_) => {
+                message_handler.eprint(&format!(
+                    "Invalid --older-than '{}': use a positive number followed by {} (e.g. \"30d\")",
+                    older_than,
+                    crate::polling::utils::DURATION_UNITS
+                ));
+                exit_handler.exit(1);
+                return Ok(());
+            }
+        };
+
+        let config = config_provider.load_config().map_err(|e| e.to_string())?;
+        let Some(pat) = crate::initialization_service::configured_pat(&config)
+            .or_else(crate::initialization_service::stored_pat)
+        else {
+            message_handler.eprint(
+                "No PAT found. Run `gh-notifier auth login` or add 'pat = \"your_token_here\"' to your config file.",
+            );
+            exit_handler.exit(1);
+            return Ok(());
+        };
+
+        let mut auth_manager = crate::AuthManager::new()?;
+        auth_manager.token_info = Some(crate::TokenInfo::from_pat(&pat));
+        auth_manager.set_github_config(config.github.clone());
+        let mut github_client = crate::GitHubClient::with_config(auth_manager, &config.github)?;
+
+        let fetched = match repository {
+            Some(repository) => {
+                github_client
+                    .get_repository_notifications(repository, None)
+                    .await
+            }
+            None => github_client.get_notifications(None, None).await,
+        };
+        let notifications = match fetched {
+            Ok(notifications) => notifications.unwrap_or_default(),
+            Err(e) => {
+                message_handler.eprint(&format!("Failed to get notifications: {}", e));
+                exit_handler.exit(1);
+                return Ok(());
+            }
+        };
+
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        let cutoff = now.saturating_sub(older_than.as_secs());
+        let stale = stale_notifications(&notifications, cutoff);
+
+        let mut marked = 0;
+        for notification in &stale {
+            match github_client
+                .mark_notification_as_read(&notification.id)
+                .await
+            {
+                Ok(()) => marked += 1,
+                Err(e) => message_handler.eprint(&format!(
+                    "Failed to mark notification {} as read: {}",
+                    notification.id, e
+                )),
+            }
+        }
+
+        message_handler.print(&format!("Marked {} notification(s) as read", marked));
+        if marked < stale.len() {
+            exit_handler.exit(1);
+        }
+        Ok(())
+    }
+
+    /// Print notification statistics from the state file
+    pub fn run_stats(
+        cumulative: bool,
+        format: crate::OutputFormat,
+        top: Option<usize>,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let state_manager = crate::StateManager::new().map_err(|e| e.to_string())?;
+        let state = &state_manager.state;
+        match format {
+            crate::OutputFormat::Text => {
+                let lines = if cumulative {
+                    crate::stats::format_counters(&state.counters, top)
+                } else {
+                    crate::stats::format_stats(&state.counters, &state.unread, top)
+                };
+                for line in lines {
+                    message_handler.print(&line);
+                }
+            }
+            crate::OutputFormat::Json => {
+                let json = if cumulative {
+                    crate::stats::counters_json(
+                        &state.counters,
+                        state_manager.get_last_checked_at(),
+                        top,
+                    )
+                } else {
+                    crate::stats::stats_json(
+                        &state.counters,
+                        &state.unread,
+                        state_manager.get_last_checked_at(),
+                        top,
+                    )
+                };
+                message_handler.print(&serde_json::to_string_pretty(&json)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Print the stored raw JSON payload of a notification
+    pub fn run_show_raw(
+        id: &str,
+        exit_handler: &dyn ExitHandler,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let store = crate::raw_payload::RawPayloadStore::with_default_dir(usize::MAX);
+        match store.load(id)? {
+            Some(payload) => message_handler.print(&serde_json::to_string_pretty(&payload)?),
+            None => {
+                message_handler.eprint(&format!(
+                    "No raw payload stored for notification {} in {}",
+                    id,
+                    store.dir().display()
+                ));
+                message_handler.eprint(
+                    "Enable 'store_raw_payload = true' in the [debug] section of your config.",
+                );
+                exit_handler.exit(1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a debug bundle for bug reports (the token is always masked)
+    pub fn run_debug_bundle(
+        config_provider: &dyn ConfigProvider,
+        output: &std::path::Path,
+        include_data: bool,
+        exit_handler: &dyn ExitHandler,
+        message_handler: &dyn MessageHandler,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config = match config_provider.load_config() {
+            Ok(config) => config,
+            Err(e) => {
+                message_handler.eprint(&format!("Failed to load config: {}", e));
+                exit_handler.exit(1);
+                return Ok(());
+            }
+        };
+
+        let entries = crate::debug_bundle::collect_entries(&config, include_data)?;
+        if let Err(e) = crate::debug_bundle::write_bundle(output, &entries) {
+            message_handler.eprint(&format!(
+                "Failed to write debug bundle to {}: {}",
+                output.display(),
+                e
+            ));
+            exit_handler.exit(1);
+            return Ok(());
+        }
+
+        message_handler.print(&format!(
+            "Wrote debug bundle to {} ({} files)",
+            output.display(),
+            entries.len()
+        ));
+        if !include_data {
+            message_handler
+                .print("Notification data is not included. Use --include-data to add it.");
+        }
+        Ok(())
     }
 
     /// Run the GitHub Notifier application with dependency injection
+    ///
+    /// With `dry_run`, notifications are fetched and filtered normally but only logged
     pub async fn run_with_deps(
+        dry_run: bool,
         config_provider: &dyn ConfigProvider,
         exit_handler: &dyn ExitHandler,
         message_handler: &dyn MessageHandler,
@@ -35,12 +514,22 @@ impl Application {
         let _guard = crate::logger::setup_logging(&config);
 
         // Initialize application components
-        let initialized_app = {
+        let mut initialized_app = {
             let service =
                 AppInitializationService::new(config_provider, exit_handler, message_handler);
             service.initialize().await?
         };
 
+        if dry_run {
+            tracing::info!("Dry run: notifications are only logged and state is not saved");
+            message_handler.print("Dry run: notifications are only logged and state is not saved.");
+            initialized_app.config = dry_run_config(initialized_app.config);
+            initialized_app.notifier = Box::new(crate::poller::DryRunNotifier);
+            initialized_app.state_manager.set_read_only(true);
+            // Don't retry mark-as-read requests left over from a previous run
+            initialized_app.state_manager.state.pending_reads = Default::default();
+        }
+
         // Run the main polling loop
         run_polling_loop_with_shutdown(
             initialized_app.config,
@@ -55,6 +544,54 @@ impl Application {
     }
 }
 
+/// Where the daemon takes the PAT from (the config file wins over the keychain)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenSource {
+    Config,
+    Keychain,
+    None,
+}
+
+fn token_source(in_config: bool, in_keychain: bool) -> TokenSource {
+    if in_config {
+        TokenSource::Config
+    } else if in_keychain {
+        TokenSource::Keychain
+    } else {
+        TokenSource::None
+    }
+}
+
+/// `cutoff`（UNIX 時刻）より前に更新された未読の通知（更新日時を解析できない通知は含めない）
+fn stale_notifications(
+    notifications: &[crate::Notification],
+    cutoff: u64,
+) -> Vec<&crate::Notification> {
+    notifications
+        .iter()
+        .filter(|n| n.unread)
+        .filter(|n| {
+            crate::polling::utils::parse_iso8601(&n.updated_at)
+                .is_ok_and(|updated_at| updated_at < cutoff)
+        })
+        .collect()
+}
+
+/// `--dry-run` 用に GitHub や外部への書き込みを行わない設定に変換
+///
+/// `auto_read_reasons` の通知は既読にせず、表示もしないように除外する
+pub(crate) fn dry_run_config(mut config: Config) -> Config {
+    config.mark_as_read_on_notify = false;
+    config.webhook.enabled = false;
+    config.notification.slack.enabled = false;
+    let auto_read_reasons = std::mem::take(&mut config.notification_filters.auto_read_reasons);
+    config
+        .notification_filters
+        .exclude_reasons
+        .extend(auto_read_reasons);
+    config
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +664,102 @@ mod tests {
         // We can't really test the exit behavior in async tests, so we'll just test the structure
         // This test can't fully validate the exit behavior, but it tests that the method exists
     }
+
+    #[test]
+    fn test_dry_run_config_disables_writes() {
+        let config = Config {
+            mark_as_read_on_notify: true,
+            webhook: crate::config::WebhookConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            notification_filters: crate::config::NotificationFilter {
+                auto_read_reasons: vec!["ci_activity".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let config = dry_run_config(config);
+        assert!(!config.mark_as_read_on_notify);
+        assert!(!config.webhook.enabled);
+        assert!(!config.notification.slack.enabled);
+        assert!(config.notification_filters.auto_read_reasons.is_empty());
+        assert!(
+            config
+                .notification_filters
+                .exclude_reasons
+                .contains(&"ci_activity".to_string())
+        );
+    }
+
+    #[test]
+    fn test_token_source() {
+        assert_eq!(token_source(true, true), TokenSource::Config);
+        assert_eq!(token_source(false, true), TokenSource::Keychain);
+        assert_eq!(token_source(false, false), TokenSource::None);
+    }
+
+    fn notification(id: &str, updated_at: &str, unread: bool) -> crate::Notification {
+        crate::Notification {
+            unread,
+            reason: "subscribed".to_string(),
+            updated_at: updated_at.to_string(),
+            ..crate::test_support::notification(id, "user/repo")
+        }
+    }
+
+    #[test]
+    fn test_stale_notifications() {
+        // 2023-01-02T00:00:00Z
+        let cutoff = 1_672_617_600;
+        let notifications = vec![
+            notification("old", "2023-01-01T00:00:00Z", true),
+            notification("old-read", "2023-01-01T00:00:00Z", false),
+            notification("recent", "2023-01-03T00:00:00Z", true),
+            notification("boundary", "2023-01-02T00:00:00Z", true),
+            notification("unparsable", "yesterday", true),
+        ];
+
+        let stale: Vec<&str> = stale_notifications(&notifications, cutoff)
+            .iter()
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(stale, vec!["old"]);
+    }
+
+    #[tokio::test]
+    async fn test_mark_read_rejects_invalid_duration() {
+        let exit_code = Arc::new(Mutex::new(0));
+        let exit_handler = MockExitHandler {
+            exit_called: Arc::new(Mutex::new(false)),
+            exit_code: exit_code.clone(),
+        };
+        let eprinted_messages = Arc::new(Mutex::new(Vec::new()));
+        let message_handler = MockMessageHandler {
+            printed_messages: Arc::new(Mutex::new(Vec::new())),
+            eprinted_messages: eprinted_messages.clone(),
+        };
+
+        for older_than in ["soon", "0d"] {
+            Application::run_mark_read(
+                older_than,
+                None,
+                &MockConfigProvider {
+                    should_error: false,
+                },
+                &exit_handler,
+                &message_handler,
+            )
+            .await
+            .unwrap();
+            assert_eq!(*exit_code.lock().unwrap(), 1);
+        }
+        let message = eprinted_messages.lock().unwrap()[0].clone();
+        assert!(message.contains("--older-than"));
+        // Every unit parse_duration accepts is listed
+        for unit in ["ms", "hr", "mo", "yr"] {
+            assert!(message.contains(unit), "{} missing from {}", unit, message);
+        }
+    }
 }
@@ -1,8 +1,11 @@
+use crate::config::GitHubConfig;
 use crate::{AuthError, TokenInfo, token_storage::TokenStorage};
 
 pub struct AuthManager {
     pub token_info: Option<TokenInfo>,
     pub(in crate::auth) token_storage: Option<TokenStorage>,
+    /// GitHub API への接続設定（トークン検証のリクエストに使用）
+    pub(in crate::auth) github_config: GitHubConfig,
 }
 
 impl AuthManager {
@@ -23,6 +26,7 @@ impl AuthManager {
         Ok(AuthManager {
             token_info: None,
             token_storage,
+            github_config: GitHubConfig::default(),
         })
     }
 
@@ -41,6 +45,12 @@ impl AuthManager {
         Ok(AuthManager {
             token_info: None,
             token_storage: Some(token_storage),
+            github_config: GitHubConfig::default(),
         })
     }
+
+    /// GitHub API への接続設定を指定
+    pub fn set_github_config(&mut self, github_config: GitHubConfig) {
+        self.github_config = github_config;
+    }
 }
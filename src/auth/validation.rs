@@ -29,15 +29,10 @@ impl super::AuthManager {
     /// This is useful to determine if a token is actually valid or if re-auth is needed
     pub async fn validate_token(&self) -> Result<bool, AuthError> {
         if let Some(ref token_info) = self.token_info {
-            let client = reqwest::Client::builder()
-                .user_agent(format!("gh-notifier/{}", env!("CARGO_PKG_VERSION")))
-                .build()
-                .map_err(|e| {
-                    AuthError::GeneralError(format!("Failed to create HTTP client: {}", e))
-                })?;
+            let client = crate::github_client::build_http_client(&self.github_config)?;
 
             let response = client
-                .get("https://api.github.com/user")
+                .get(self.github_config.api_url("/user"))
                 .header(
                     "Authorization",
                     format!("token {}", token_info.access_token.expose_secret()),
@@ -0,0 +1,144 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+/// GitHub notification desktop daemon
+#[derive(Debug, Parser)]
+#[command(name = "gh-notifier", version, about)]
+pub struct Cli {
+    /// Use a named profile with its own config, state and token
+    /// (stored under `<config_dir>/gh-notifier/profiles/<NAME>/`)
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Load the config from this file instead of the profile's `config.toml`
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Run the daemon without showing notifications, marking them read,
+    /// posting webhooks or saving state; log what would be notified instead
+    #[arg(long)]
+    pub dry_run: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+}
+
+/// Subcommands (the daemon runs when none is given)
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// Check config, token, GitHub connectivity and desktop notifications
+    Doctor,
+
+    /// Show notification statistics
+    Stats {
+        /// Show only the cumulative counters kept across restarts (without unread counts)
+        #[arg(long)]
+        cumulative: bool,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+
+        /// Show only the N largest entries of each breakdown
+        #[arg(long, value_name = "N")]
+        top: Option<usize>,
+    },
+
+    /// Configuration file helpers
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Manage the GitHub credential
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommands,
+    },
+
+    /// Mark unread notifications older than a duration as read on GitHub
+    MarkRead {
+        /// Only notifications last updated longer ago than this (e.g. "30d", "12h")
+        #[arg(long, value_name = "DURATION")]
+        older_than: String,
+
+        /// Only notifications from this repository
+        #[arg(long, value_name = "OWNER/REPO")]
+        repository: Option<String>,
+    },
+
+    /// Filter configuration helpers
+    Filters {
+        #[command(subcommand)]
+        command: FiltersCommands,
+    },
+
+    /// Debugging helpers
+    Debug {
+        #[command(subcommand)]
+        command: DebugCommands,
+    },
+}
+
+/// Output format of commands that print data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    Text,
+    /// JSON with a stable schema for scripts and dashboards
+    Json,
+}
+
+/// `auth` subcommands
+#[derive(Debug, Subcommand)]
+pub enum AuthCommands {
+    /// Enter a new PAT, verify it and replace only the `pat` in the config file
+    /// (state and other settings are kept)
+    Login {
+        /// Save the PAT to the OS keychain (or the token file) instead of the config file
+        #[arg(long)]
+        keychain: bool,
+    },
+    /// Show whether a PAT is configured and where it comes from (never prints the token)
+    Status,
+    /// Delete the PAT saved with `auth login --keychain`
+    Logout,
+}
+
+/// `config` subcommands
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommands {
+    /// Load and validate the config file without starting the daemon,
+    /// then print the parsed settings with the token masked
+    Validate,
+}
+
+/// `filters` subcommands
+#[derive(Debug, Subcommand)]
+pub enum FiltersCommands {
+    /// Suggest `include_repositories` from the repositories you watch on GitHub
+    FromGithub,
+}
+
+/// `debug` subcommands
+#[derive(Debug, Subcommand)]
+pub enum DebugCommands {
+    /// Print the raw JSON GitHub sent for a notification
+    /// (requires `debug.store_raw_payload = true`)
+    ShowRaw {
+        /// Notification (thread) ID
+        id: String,
+    },
+
+    /// Write a zip with version, system info, the token-masked config and recent logs
+    /// for attaching to bug reports
+    Bundle {
+        /// Output zip file
+        #[arg(long, value_name = "FILE")]
+        output: PathBuf,
+
+        /// Also include the state file and stored raw payloads (notification titles, etc.)
+        #[arg(long)]
+        include_data: bool,
+    },
+}
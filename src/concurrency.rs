@@ -0,0 +1,67 @@
+use std::future::Future;
+use std::sync::Arc;
+
+/// `items` のそれぞれに対する `task` を同時に最大 `max_concurrent` 件で実行し、結果を `items` と同じ順で返す
+///
+/// 実行中に panic したタスクの結果は `None` になる
+pub async fn run_bounded<T, R, F, Fut>(
+    items: Vec<T>,
+    max_concurrent: usize,
+    task: F,
+) -> Vec<Option<R>>
+where
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = R> + Send + 'static,
+    R: Send + 'static,
+{
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(max_concurrent.max(1)));
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, item) in items.into_iter().enumerate() {
+        let semaphore = semaphore.clone();
+        let future = task(item);
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            (index, future.await)
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((index, result)) => results[index] = Some(result),
+            Err(e) => tracing::warn!("Concurrent task failed: {}", e),
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_run_bounded_keeps_order_and_limit() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        let results = run_bounded((0..8).collect(), 2, |i: u64| {
+            let (running, max_running) = (running.clone(), max_running.clone());
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now, Ordering::SeqCst);
+                // 後の要素ほど早く終わるようにして、結果が入力の順に並ぶことを確認する
+                tokio::time::sleep(std::time::Duration::from_millis(10 * (8 - i))).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                i * 10
+            }
+        })
+        .await;
+
+        assert_eq!(
+            results,
+            (0..8).map(|i| Some(i * 10)).collect::<Vec<Option<u64>>>()
+        );
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    }
+}
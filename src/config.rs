@@ -1,3 +1,4 @@
+use crate::polling::priority::NotificationPriority;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
@@ -22,6 +23,10 @@ pub struct NotificationFilter {
     #[serde(default)]
     pub include_organizations: Vec<String>,
 
+    /// `include_organizations` が空の場合に、起動時に GitHub から取得した自分が所属する組織のリポジトリのみ通知するかどうか
+    #[serde(default)]
+    pub member_organizations_only: bool,
+
     /// 除外する組織のリスト（このリストに含まれる組織のリポジトリは通知されない）
     #[serde(default)]
     pub exclude_organizations: Vec<String>,
@@ -47,6 +52,14 @@ pub struct NotificationFilter {
     #[serde(default)]
     pub include_reasons: Vec<String>,
 
+    /// 含める通知の理由の正規表現のリスト（例: ".*_mention"）。理由全体が一致した場合に含める
+    #[serde(default)]
+    pub include_reason_patterns: Vec<String>,
+
+    /// 除外する通知の理由の正規表現のリスト。理由全体が一致した場合に除外
+    #[serde(default)]
+    pub exclude_reason_patterns: Vec<String>,
+
     // 新しいコンテンツベースのフィルター
     /// 通知タイトルに含まれるべきキーワードのリスト
     #[serde(default)]
@@ -60,6 +73,14 @@ pub struct NotificationFilter {
     #[serde(default)]
     pub repository_contains: Vec<String>,
 
+    /// 含めるラベルのリスト（指定がある場合、いずれかのラベルが付いた PR・Issue の通知のみ通知）。照合のために API でラベルを取得する
+    #[serde(default)]
+    pub include_labels: Vec<String>,
+
+    /// 除外するラベルのリスト（いずれかのラベルが付いた PR・Issue の通知は通知されない）
+    #[serde(default)]
+    pub exclude_labels: Vec<String>,
+
     /// 参加したスレッドの通知を除外するかどうか
     #[serde(default)]
     pub exclude_participating: bool,
@@ -72,6 +93,80 @@ pub struct NotificationFilter {
     /// ドラフトPRの通知を除外するかどうか
     #[serde(default)]
     pub exclude_draft_prs: bool,
+
+    /// タイトルが空（空白のみを含む）の通知を除外するかどうか
+    #[serde(default)]
+    pub exclude_empty_titles: bool,
+
+    /// 無視するタイトルのリスト（例: "(no subject)"）。タイトル全体が一致した場合に除外（大文字小文字は区別しない）
+    #[serde(default)]
+    pub ignore_title_patterns: Vec<String>,
+
+    /// 表示せずに既読として扱う通知の理由のリスト（例: "subscribed"）。除外とは異なり、GitHub 上で既読にされる
+    #[serde(default)]
+    pub auto_read_reasons: Vec<String>,
+
+    /// include_repositories をあいまい一致で照合するかどうか（exclude_repositories は常に完全一致で照合する）
+    #[serde(default)]
+    pub fuzzy_match_repositories: bool,
+
+    /// include_organizations をあいまい一致で照合するかどうか（exclude_organizations は常に完全一致で照合する）
+    #[serde(default)]
+    pub fuzzy_match_organizations: bool,
+}
+
+/// ポーリングの設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollingConfig {
+    /// 起動してから最初のポーリングまでの待機時間（秒）
+    #[serde(default)]
+    pub startup_delay_sec: u64,
+
+    /// 起動時の待機時間に加えるランダムな揺らぎの最大値（秒）。多数のマシンが同時に起動した場合の負荷を分散する
+    #[serde(default)]
+    pub startup_jitter_sec: u64,
+
+    /// API のレート制限の残りが少なくなった場合に、リセット時刻まで残りのリクエストを分散するようにポーリング間隔を延ばすかどうか
+    #[serde(default)]
+    pub adaptive_backoff: bool,
+
+    /// `adaptive_backoff` が有効な場合に、ポーリング間隔を延ばし始めるレート制限の残りリクエスト数
+    #[serde(default = "default_rate_limit_threshold")]
+    pub rate_limit_threshold: u64,
+
+    /// ポーリングを行う時間帯（ローカル時刻、例: "09:00-18:00"）。時間帯の外ではポーリングを停止し、再開時にまとめて取得する
+    #[serde(default)]
+    pub active_hours: Option<String>,
+
+    /// ポーリングを行う曜日（例: ["mon", "tue", "wed", "thu", "fri"]）。空の場合は毎日
+    #[serde(default)]
+    pub active_days: Vec<String>,
+
+    /// 通知ごとのリクエスト（ラベルの取得、既読にする処理、Webhook・Slack への送信）の同時リクエスト数の上限
+    #[serde(default = "default_max_concurrent")]
+    pub max_concurrent: usize,
+}
+
+fn default_rate_limit_threshold() -> u64 {
+    100
+}
+
+fn default_max_concurrent() -> usize {
+    4
+}
+
+impl Default for PollingConfig {
+    fn default() -> Self {
+        PollingConfig {
+            startup_delay_sec: 0,
+            startup_jitter_sec: 0,
+            adaptive_backoff: false,
+            rate_limit_threshold: default_rate_limit_threshold(),
+            active_hours: None,
+            active_days: Vec::new(),
+            max_concurrent: default_max_concurrent(),
+        }
+    }
 }
 
 /// 通知バッチ処理の設定
@@ -84,6 +179,27 @@ pub struct NotificationBatchConfig {
     /// バッチ処理の間隔（秒）
     #[serde(default = "default_batch_interval_sec")]
     pub batch_interval_sec: u64,
+
+    /// バッチ内の通知を表示する順序
+    #[serde(default)]
+    pub sort_order: BatchSortOrder,
+
+    /// バッチ内の同じリポジトリの通知を 1 件の通知にまとめるかどうか（`notification.group_by` が none の場合のみ有効）
+    #[serde(default)]
+    pub group_by_repository: bool,
+}
+
+/// バッチ内の通知の表示順序（通知の一覧の上から下への順序。最後に送信した通知が一番上に表示される）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchSortOrder {
+    /// 優先度の高い順（同じ優先度の場合は新しい順）。最も重要な通知を最後に送信する
+    #[default]
+    PriorityDesc,
+    /// 新しい順（`updated_at` が最も新しい通知を最後に送信する）
+    TimeDesc,
+    /// 並べ替えずに受信した順に送信する
+    Arrival,
 }
 
 fn default_batch_size() -> usize {
@@ -99,10 +215,459 @@ impl Default for NotificationBatchConfig {
         NotificationBatchConfig {
             batch_size: default_batch_size(),
             batch_interval_sec: default_batch_interval_sec(),
+            sort_order: BatchSortOrder::default(),
+            group_by_repository: false,
+        }
+    }
+}
+
+/// 起動時にトークンが設定されていない場合の動作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingTokenBehavior {
+    /// エラーメッセージを表示して終了する
+    #[default]
+    Error,
+    /// ターミナルでトークンの入力を求める（ターミナルでない場合は終了する）
+    Prompt,
+    /// ログに出力し、設定ファイルにトークンが追加されるまで待機する（デーモン向け）
+    Wait,
+    /// OAuth のデバイスフローで認証する（未対応のため、現状はエラーメッセージを表示して終了する）
+    DeviceFlow,
+}
+
+/// 認証の設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthConfig {
+    /// 起動時にトークンが設定されていない場合の動作（error, prompt, wait, device_flow）
+    #[serde(default)]
+    pub on_missing_token: MissingTokenBehavior,
+
+    /// `wait` の場合に設定ファイルを再確認する間隔（秒）
+    #[serde(default = "default_missing_token_retry_sec")]
+    pub missing_token_retry_sec: u64,
+}
+
+fn default_missing_token_retry_sec() -> u64 {
+    60
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        AuthConfig {
+            on_missing_token: MissingTokenBehavior::default(),
+            missing_token_retry_sec: default_missing_token_retry_sec(),
+        }
+    }
+}
+
+/// GitHub API への接続設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubConfig {
+    /// 追加で信頼する CA 証明書（PEM 形式）のパス。TLS を中継するプロキシ環境向け
+    #[serde(default)]
+    pub ca_bundle: Option<String>,
+
+    /// TLS 証明書の検証を無効にするかどうか（危険: 中間者攻撃を検出できなくなる）
+    #[serde(default)]
+    pub danger_accept_invalid_certs: bool,
+
+    /// GitHub API のベース URL。GitHub Enterprise Server の場合は `https://<ホスト>/api/v3`
+    /// （未設定の場合は `https://api.github.com`）
+    #[serde(default)]
+    pub api_base_url: Option<String>,
+
+    /// 通知の一覧を取得する際に `Link` ヘッダーをたどるページ数の上限（1ページ目を含む）
+    #[serde(default = "default_max_notification_pages")]
+    pub max_notification_pages: usize,
+
+    /// 既読の通知も取得するかどうか（`?all=true`）。既読にしたスレッドが更新された場合も通知する
+    #[serde(default)]
+    pub show_read_notifications: bool,
+
+    /// 自分が直接参加している（メンション・レビュー依頼・コメントなど）通知のみを取得するかどうか（`?participating=true`）
+    #[serde(default)]
+    pub participating_only: bool,
+
+    /// 通知の取得が一時的なエラー（接続エラー、5xx、レート制限）で失敗した場合の再試行回数。0 の場合は再試行しない
+    #[serde(default)]
+    pub retry_count: u32,
+
+    /// 再試行の間隔の基準（秒）。再試行ごとに2倍にし、ランダムな揺らぎを加える
+    #[serde(default = "default_retry_base_sec")]
+    pub retry_base_sec: u64,
+
+    /// 再試行の間隔の上限（秒）
+    #[serde(default = "default_retry_max_sec")]
+    pub retry_max_sec: u64,
+}
+
+fn default_max_notification_pages() -> usize {
+    10
+}
+
+fn default_retry_base_sec() -> u64 {
+    1
+}
+
+fn default_retry_max_sec() -> u64 {
+    60
+}
+
+impl Default for GitHubConfig {
+    fn default() -> Self {
+        GitHubConfig {
+            ca_bundle: None,
+            danger_accept_invalid_certs: false,
+            api_base_url: None,
+            max_notification_pages: default_max_notification_pages(),
+            show_read_notifications: false,
+            participating_only: false,
+            retry_count: 0,
+            retry_base_sec: default_retry_base_sec(),
+            retry_max_sec: default_retry_max_sec(),
+        }
+    }
+}
+
+/// github.com の GitHub API のベース URL
+pub const DEFAULT_API_BASE_URL: &str = "https://api.github.com";
+
+/// github.com の Web のページのベース URL
+pub const DEFAULT_WEB_BASE_URL: &str = "https://github.com";
+
+/// API のベース URL から Web のページのベース URL を導出
+///
+/// - `https://api.github.com` → `https://github.com`
+/// - `https://ghe.example.com/api/v3`（GitHub Enterprise Server）→ `https://ghe.example.com`
+pub fn web_base_url_for(api_base: &str) -> Option<String> {
+    let api_base = api_base.trim_end_matches('/');
+    if api_base == DEFAULT_API_BASE_URL {
+        return Some(DEFAULT_WEB_BASE_URL.to_string());
+    }
+    let web_base = api_base.strip_suffix("/api/v3")?;
+    (web_base.starts_with("https://") || web_base.starts_with("http://"))
+        .then(|| web_base.to_string())
+}
+
+impl GitHubConfig {
+    /// GitHub API のベース URL（末尾の `/` は取り除く）
+    pub fn api_base_url(&self) -> &str {
+        self.api_base_url
+            .as_deref()
+            .map(|url| url.trim_end_matches('/'))
+            .filter(|url| !url.is_empty())
+            .unwrap_or(DEFAULT_API_BASE_URL)
+    }
+
+    /// Web のページ（通知一覧・リポジトリなど）のベース URL
+    ///
+    /// `api_base_url` から導出できない場合は github.com
+    pub fn web_base_url(&self) -> String {
+        web_base_url_for(self.api_base_url()).unwrap_or_else(|| DEFAULT_WEB_BASE_URL.to_string())
+    }
+
+    /// ベース URL に API のパス（`/notifications` など）を結合
+    pub fn api_url(&self, path: &str) -> String {
+        format!("{}{}", self.api_base_url(), path)
+    }
+
+    /// 通知の一覧を取得する URL（`show_read_notifications` / `participating_only` のクエリを含む）
+    ///
+    /// `path` には `/notifications` または `/repos/{owner}/{repo}/notifications` を指定する
+    pub fn notifications_url(&self, path: &str) -> String {
+        let mut query = Vec::new();
+        if self.show_read_notifications {
+            query.push("all=true");
+        }
+        if self.participating_only {
+            query.push("participating=true");
+        }
+
+        let url = self.api_url(path);
+        if query.is_empty() {
+            url
+        } else {
+            format!("{}?{}", url, query.join("&"))
+        }
+    }
+}
+
+/// デスクトップ通知の送信モード
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationMode {
+    /// 通知ごとにデスクトップ通知を表示する（従来の動作）
+    #[default]
+    Individual,
+    /// 個別の通知は表示せず、未読件数のサマリーを定期的に表示する
+    Digest,
+    /// デスクトップ通知を一切表示しない
+    Off,
+}
+
+/// バッチ通知・ダイジェストで通知をまとめる単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    /// まとめない（ダイジェストはリポジトリ単位）
+    #[default]
+    None,
+    /// リポジトリ単位
+    Repository,
+    /// 組織（オーナー）単位
+    Organization,
+    /// 通知の理由（reason）単位
+    Reason,
+}
+
+/// OS のおやすみモード中の通知の扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DndMode {
+    /// OS のおやすみモードを検出し、有効な間はデスクトップ通知の表示を抑制する（検出できない環境では抑制しない）
+    #[default]
+    Auto,
+    /// 常にデスクトップ通知の表示を抑制する（手動でおやすみモードにする）
+    On,
+    /// おやすみモードに関係なく通知を表示する
+    Off,
+}
+
+/// デスクトップ通知の設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationConfig {
+    /// 通知の送信モード（individual, digest, off）
+    #[serde(default)]
+    pub mode: NotificationMode,
+
+    /// ダイジェストモードでサマリーを表示する間隔（秒）
+    #[serde(default = "default_digest_interval_sec")]
+    pub digest_interval_sec: u64,
+
+    /// デーモンの起動・終了時に通知を表示するかどうか
+    #[serde(default)]
+    pub announce_lifecycle: bool,
+
+    /// 低優先度の通知を自動的に閉じるまでの時間（ミリ秒）。高優先度の通知は閉じるまで表示される
+    #[serde(default = "default_low_priority_timeout_ms")]
+    pub low_priority_timeout_ms: u32,
+
+    /// OS のおやすみモード中の通知の扱い（auto, on, off）
+    #[serde(default)]
+    pub dnd: DndMode,
+
+    /// 同じスレッドを再度デスクトップ通知するまでの最小間隔（秒）。0 の場合は無効
+    /// （`dedupe_window_sec` も同じ意味の別名として受け付ける）
+    #[serde(default, alias = "dedupe_window_sec")]
+    pub thread_cooldown_sec: u64,
+
+    /// サウンドを鳴らす最低の優先度（low, normal, high）。未設定の場合はサウンドを鳴らさない
+    /// （`sound_on_priority` も同じ意味の別名として受け付ける）
+    #[serde(default, alias = "sound_on_priority")]
+    pub sound_min_priority: Option<NotificationPriority>,
+
+    /// サウンドとして再生するファイルのパス。未設定の場合はサウンドテーマの通知音
+    #[serde(default)]
+    pub sound_file: Option<String>,
+
+    /// バッチ通知・ダイジェストで通知をまとめる単位（none, repository, organization, reason）
+    #[serde(default)]
+    pub group_by: GroupBy,
+
+    /// デスクトップ通知の本文の最大行数（超えた分は省略）。未設定の場合は制限しない
+    #[serde(default)]
+    pub body_max_lines: Option<usize>,
+
+    /// デスクトップ通知の本文の最大文字数（単語の境界で省略）。未設定の場合は制限しない
+    #[serde(default)]
+    pub body_max_chars: Option<usize>,
+
+    /// デスクトップ通知のタイトルのテンプレート（例: "{repo} — {reason}: {title}"）。未設定の場合はデフォルトの形式
+    #[serde(default)]
+    pub title_template: Option<String>,
+
+    /// デスクトップ通知の本文のテンプレート。未設定の場合はデフォルトの形式
+    #[serde(default)]
+    pub body_template: Option<String>,
+
+    /// デスクトップ通知を抑制する時間帯（ローカル時刻、例: "22:00-07:00"）。ポーリングは継続し、通知は GitHub 上で未読のまま残す
+    #[serde(default)]
+    pub quiet_hours: Option<String>,
+
+    /// `quiet_hours` を適用する曜日（例: ["mon", "tue", "wed", "thu", "fri"]）。空の場合は毎日
+    #[serde(default)]
+    pub quiet_days: Vec<String>,
+
+    /// 通知の理由ごとの優先度（例: subscribed = "low"）。指定していない理由はデフォルトの優先度
+    #[serde(default)]
+    pub priority: std::collections::BTreeMap<String, NotificationPriority>,
+
+    /// Slack の Incoming Webhook への投稿の設定
+    #[serde(default)]
+    pub slack: SlackConfig,
+}
+
+/// 新しい通知を Slack の Incoming Webhook に投稿する設定（`[notification.slack]`）
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SlackConfig {
+    /// Slack に投稿するかどうか
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Incoming Webhook の URL
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+
+    /// true の場合はデスクトップ通知を表示せず、Slack にのみ投稿する
+    #[serde(default)]
+    pub replace_desktop: bool,
+}
+
+fn default_digest_interval_sec() -> u64 {
+    3600
+}
+
+fn default_low_priority_timeout_ms() -> u32 {
+    5000
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        NotificationConfig {
+            mode: NotificationMode::default(),
+            digest_interval_sec: default_digest_interval_sec(),
+            announce_lifecycle: false,
+            low_priority_timeout_ms: default_low_priority_timeout_ms(),
+            dnd: DndMode::default(),
+            thread_cooldown_sec: 0,
+            sound_min_priority: None,
+            sound_file: None,
+            group_by: GroupBy::default(),
+            body_max_lines: None,
+            body_max_chars: None,
+            title_template: None,
+            body_template: None,
+            quiet_hours: None,
+            quiet_days: Vec::new(),
+            priority: std::collections::BTreeMap::new(),
+            slack: SlackConfig::default(),
+        }
+    }
+}
+
+/// フィルタで除外された通知のサマリーの設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilteredSummaryConfig {
+    /// 除外された通知のサマリーを定期的に出力するかどうか
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// サマリーを出力する間隔（秒）
+    #[serde(default = "default_filtered_summary_interval_sec")]
+    pub interval_sec: u64,
+
+    /// ログに加えて低優先度のデスクトップ通知としても表示するかどうか
+    #[serde(default)]
+    pub desktop_notification: bool,
+}
+
+fn default_filtered_summary_interval_sec() -> u64 {
+    3600
+}
+
+impl Default for FilteredSummaryConfig {
+    fn default() -> Self {
+        FilteredSummaryConfig {
+            enabled: false,
+            interval_sec: default_filtered_summary_interval_sec(),
+            desktop_notification: false,
+        }
+    }
+}
+
+/// 日時の表示形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeFormat {
+    /// 相対時間で表示（例: "5m ago"）
+    #[default]
+    Relative,
+    /// 絶対時刻で表示（例: "2023-01-01 21:00 +09:00"）
+    Absolute,
+}
+
+/// 表示に関する設定
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DisplayConfig {
+    /// 日時の表示形式（relative, absolute）
+    #[serde(default)]
+    pub time_format: TimeFormat,
+
+    /// 表示に使用するタイムゾーン（"local", "utc", "+09:00" など。省略時はシステムのローカルタイムゾーン）
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+/// 通知の累計件数の設定
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StatsConfig {
+    /// 月が変わった時点で累計件数をリセットするかどうか
+    #[serde(default)]
+    pub reset_monthly: bool,
+}
+
+/// リポジトリごとのポーリング設定（`[[repository_overrides]]`）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RepositoryOverride {
+    /// 対象のリポジトリ（`owner/repo`）
+    pub repository: String,
+
+    /// このリポジトリの通知を個別に取得する間隔（秒）。`poll_interval_sec` より短い場合のみ有効
+    pub poll_interval_sec: u64,
+}
+
+/// デバッグ用の設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugConfig {
+    /// GitHub から受信した通知の生の JSON を保存するかどうか（`gh-notifier debug show-raw <ID>` で参照可能）
+    #[serde(default)]
+    pub store_raw_payload: bool,
+
+    /// 保存する生の JSON の最大件数（古いものから削除）
+    #[serde(default = "default_raw_payload_max_entries")]
+    pub raw_payload_max_entries: usize,
+}
+
+fn default_raw_payload_max_entries() -> usize {
+    500
+}
+
+impl Default for DebugConfig {
+    fn default() -> Self {
+        DebugConfig {
+            store_raw_payload: false,
+            raw_payload_max_entries: default_raw_payload_max_entries(),
         }
     }
 }
 
+/// 新しい通知を外部の URL に POST する Webhook の設定
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct WebhookConfig {
+    /// Webhook を送信するかどうか
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 送信先の URL
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// 本文の HMAC-SHA256 署名（`X-GH-Notifier-Signature` ヘッダー）に使用する秘密鍵
+    #[serde(default)]
+    pub secret: Option<String>,
+}
+
 /// ポーリング処理のエラーハンドリング設定
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PollingErrorHandlingConfig {
@@ -147,18 +712,58 @@ pub struct Config {
     #[serde(default)]
     pub pat: Option<String>,
 
+    /// 認証の設定
+    #[serde(default)]
+    pub auth: AuthConfig,
+
+    /// GitHub API への接続設定
+    #[serde(default)]
+    pub github: GitHubConfig,
+
     /// 通知フィルタの設定
     #[serde(default)]
     pub notification_filters: NotificationFilter,
 
+    /// ポーリングの設定
+    #[serde(default)]
+    pub polling: PollingConfig,
+
     /// 通知バッチ処理の設定
     #[serde(default)]
     pub notification_batch_config: NotificationBatchConfig,
 
+    /// デスクトップ通知の設定
+    #[serde(default)]
+    pub notification: NotificationConfig,
+
+    /// フィルタで除外された通知のサマリーの設定
+    #[serde(default)]
+    pub filtered_summary: FilteredSummaryConfig,
+
+    /// 表示に関する設定
+    #[serde(default)]
+    pub display: DisplayConfig,
+
     /// ポーリング処理のエラーハンドリング設定
     #[serde(default)]
     pub polling_error_handling_config: PollingErrorHandlingConfig,
 
+    /// 通知の累計件数の設定
+    #[serde(default)]
+    pub stats: StatsConfig,
+
+    /// リポジトリごとのポーリング間隔の設定
+    #[serde(default)]
+    pub repository_overrides: Vec<RepositoryOverride>,
+
+    /// Webhook の設定
+    #[serde(default)]
+    pub webhook: WebhookConfig,
+
+    /// デバッグ用の設定
+    #[serde(default)]
+    pub debug: DebugConfig,
+
     /// ログレベル（省略可、デフォルト: info）
     #[serde(default = "default_log_level")]
     pub log_level: String,
@@ -194,31 +799,42 @@ impl Default for Config {
             poll_interval_sec: default_poll_interval_sec(),
             mark_as_read_on_notify: default_mark_as_read_on_notify(),
             pat: None,
+            auth: AuthConfig::default(),
+            github: GitHubConfig::default(),
             notification_filters,
+            polling: PollingConfig::default(),
             notification_batch_config: NotificationBatchConfig::default(),
+            notification: NotificationConfig::default(),
+            filtered_summary: FilteredSummaryConfig::default(),
+            display: DisplayConfig::default(),
             polling_error_handling_config: PollingErrorHandlingConfig::default(),
+            stats: StatsConfig::default(),
+            repository_overrides: Vec::new(),
+            webhook: WebhookConfig::default(),
+            debug: DebugConfig::default(),
             log_level: default_log_level(),
             log_file_path: None,
         }
     }
 }
 
-/// 設定ファイルのパスを取得
+/// 設定ファイルのパスを取得（`--profile` が指定されている場合はプロファイルのディレクトリ）
 fn config_file_path() -> PathBuf {
-    let mut path = dirs::config_dir()
-        .unwrap_or_else(|| std::env::current_dir().expect("現在のディレクトリが取得できません"));
-    path.push("gh-notifier");
-    path.push("config.toml");
-    path
+    crate::paths::config_file_path()
 }
 
-/// 設定ファイルを読み込む
+/// 設定ファイルを読み込む（`--config` が指定されている場合はそのファイル）
 pub fn load_config() -> Result<Config, Box<dyn std::error::Error>> {
+    if let Some(path) = crate::paths::config_path_override() {
+        return load_config_from(path);
+    }
+
     let config_path = config_file_path();
 
     if config_path.exists() {
         let contents = fs::read_to_string(config_path)?;
         let config: Config = toml::from_str(&contents)?;
+        validate_config(&config)?;
         Ok(config)
     } else {
         // ファイルが存在しない場合はデフォルト設定を返す
@@ -226,6 +842,178 @@ pub fn load_config() -> Result<Config, Box<dyn std::error::Error>> {
     }
 }
 
+/// 指定したパスの設定ファイルを読み込む
+///
+/// 明示的に指定されたパスのため、ファイルが存在しない場合はデフォルト設定を使わずにエラーにする
+pub fn load_config_from(path: &std::path::Path) -> Result<Config, Box<dyn std::error::Error>> {
+    if !path.is_file() {
+        return Err(format!("Config file '{}' does not exist", path.display()).into());
+    }
+
+    let contents = fs::read_to_string(path)?;
+    let config: Config = toml::from_str(&contents)?;
+    validate_config(&config)?;
+    Ok(config)
+}
+
+/// 設定としては有効だが意図しない動作になりうる項目への警告
+pub fn config_warnings(config: &Config) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if config
+        .pat
+        .as_deref()
+        .is_none_or(|pat| pat.trim().is_empty())
+    {
+        warnings.push("pat is not set; the daemon cannot authenticate with GitHub".to_string());
+    }
+
+    if config.github.danger_accept_invalid_certs {
+        warnings.push(
+            "github.danger_accept_invalid_certs disables TLS certificate verification".to_string(),
+        );
+    }
+
+    for repository_override in &config.repository_overrides {
+        if repository_override.poll_interval_sec >= config.poll_interval_sec {
+            warnings.push(format!(
+                "repository_overrides: '{}' is ignored because its poll_interval_sec ({}s) is not shorter than poll_interval_sec ({}s)",
+                repository_override.repository,
+                repository_override.poll_interval_sec,
+                config.poll_interval_sec
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// 読み込んだ設定の値を検証する
+fn validate_config(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(timezone) = &config.display.timezone {
+        crate::polling::utils::parse_timezone(timezone)
+            .map_err(|e| format!("Invalid display.timezone '{}': {}", timezone, e))?;
+    }
+
+    if let Some(ca_bundle) = &config.github.ca_bundle
+        && !std::path::Path::new(ca_bundle).is_file()
+    {
+        return Err(format!("github.ca_bundle '{}' does not exist", ca_bundle).into());
+    }
+
+    if let Some(sound_file) = &config.notification.sound_file
+        && !std::path::Path::new(sound_file).is_file()
+    {
+        return Err(format!("notification.sound_file '{}' does not exist", sound_file).into());
+    }
+
+    if let Some(api_base_url) = &config.github.api_base_url
+        && !(api_base_url.starts_with("https://") || api_base_url.starts_with("http://"))
+    {
+        return Err(format!(
+            "github.api_base_url '{}' must start with https:// or http://",
+            api_base_url
+        )
+        .into());
+    }
+
+    crate::polling::active_hours::ActiveSchedule::from_config(&config.polling)?;
+    crate::polling::active_hours::ActiveSchedule::from_quiet_hours(&config.notification)?;
+
+    if config.polling.max_concurrent == 0 {
+        return Err("polling.max_concurrent must be at least 1".into());
+    }
+
+    for (field, template) in [
+        ("title_template", &config.notification.title_template),
+        ("body_template", &config.notification.body_template),
+    ] {
+        if let Some(template) = template {
+            crate::polling::template::validate(template)
+                .map_err(|e| format!("notification.{}: {}", field, e))?;
+        }
+    }
+
+    if config.webhook.enabled {
+        match config.webhook.url.as_deref() {
+            Some(url) if url.starts_with("https://") || url.starts_with("http://") => {}
+            Some(url) => {
+                return Err(
+                    format!("webhook.url '{}' must start with https:// or http://", url).into(),
+                );
+            }
+            None => return Err("webhook.url is required when webhook.enabled = true".into()),
+        }
+    }
+
+    if config.notification.slack.enabled {
+        match config.notification.slack.webhook_url.as_deref() {
+            Some(url) if url.starts_with("https://") => {}
+            Some(url) => {
+                return Err(format!(
+                    "notification.slack.webhook_url '{}' must start with https://",
+                    url
+                )
+                .into());
+            }
+            None => {
+                return Err(
+                    "notification.slack.webhook_url is required when notification.slack.enabled = true"
+                        .into(),
+                );
+            }
+        }
+    }
+
+    for (field, patterns) in [
+        (
+            "include_reason_patterns",
+            &config.notification_filters.include_reason_patterns,
+        ),
+        (
+            "exclude_reason_patterns",
+            &config.notification_filters.exclude_reason_patterns,
+        ),
+    ] {
+        for pattern in patterns {
+            crate::polling::filters::reason_filter::compile_reason_pattern(pattern).map_err(
+                |e| {
+                    format!(
+                        "Invalid notification_filters.{} '{}': {}",
+                        field, pattern, e
+                    )
+                },
+            )?;
+        }
+    }
+
+    for repository_override in &config.repository_overrides {
+        let valid_name =
+            repository_override
+                .repository
+                .split_once('/')
+                .is_some_and(|(owner, repo)| {
+                    !owner.is_empty() && !repo.is_empty() && !repo.contains('/')
+                });
+        if !valid_name {
+            return Err(format!(
+                "repository_overrides: '{}' is not in 'owner/repo' form",
+                repository_override.repository
+            )
+            .into());
+        }
+        if repository_override.poll_interval_sec == 0 {
+            return Err(format!(
+                "repository_overrides: poll_interval_sec for '{}' must be greater than 0",
+                repository_override.repository
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
 /// 設定ファイルを保存する
 pub fn save_config(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
     let config_path = config_file_path();
@@ -241,6 +1029,62 @@ pub fn save_config(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// 設定ファイルの `pat` のみを置き換える（他の設定やコメントはそのまま残す）
+pub fn save_pat(pat: &str) -> Result<(), Box<dyn std::error::Error>> {
+    save_pat_to(&config_file_path(), pat)
+}
+
+/// 指定したパスの設定ファイルの `pat` のみを置き換える（ファイルがない場合は作成）
+pub fn save_pat_to(
+    config_path: &std::path::Path,
+    pat: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let contents = if config_path.exists() {
+        fs::read_to_string(config_path)?
+    } else {
+        if let Some(parent_dir) = config_path.parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+        String::new()
+    };
+
+    fs::write(config_path, replace_pat(&contents, pat))?;
+    Ok(())
+}
+
+/// 設定ファイルの内容のトップレベルの `pat = ...` を置き換え、ない場合は先頭に追加
+fn replace_pat(contents: &str, pat: &str) -> String {
+    let pat_line = format!("pat = {}", toml::Value::String(pat.to_string()));
+    let mut lines: Vec<String> = Vec::new();
+    let mut replaced = false;
+    let mut in_table = false;
+
+    for line in contents.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('[') {
+            in_table = true;
+        }
+        let is_pat = !in_table
+            && trimmed
+                .strip_prefix("pat")
+                .is_some_and(|rest| rest.trim_start().starts_with('='));
+        if is_pat && !replaced {
+            lines.push(pat_line.clone());
+            replaced = true;
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+
+    if !replaced {
+        lines.insert(0, pat_line);
+    }
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,6 +1154,243 @@ mod tests {
         assert!(config.mark_as_read_on_notify);
     }
 
+    #[test]
+    fn test_notification_mode() {
+        let config = Config::default();
+        assert_eq!(config.notification.mode, NotificationMode::Individual);
+        assert_eq!(config.notification.digest_interval_sec, 3600);
+
+        let toml_str = r#"
+            [notification]
+            mode = "digest"
+            digest_interval_sec = 600
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.notification.mode, NotificationMode::Digest);
+        assert_eq!(config.notification.digest_interval_sec, 600);
+
+        let config: Config = toml::from_str("[notification]\nmode = \"off\"").unwrap();
+        assert_eq!(config.notification.mode, NotificationMode::Off);
+    }
+
+    #[test]
+    fn test_github_web_base_url() {
+        assert_eq!(GitHubConfig::default().web_base_url(), "https://github.com");
+
+        let config: Config =
+            toml::from_str("[github]\napi_base_url = \"https://ghe.example.com/api/v3/\"").unwrap();
+        assert_eq!(config.github.web_base_url(), "https://ghe.example.com");
+
+        // 導出できない場合は github.com
+        let config: Config =
+            toml::from_str("[github]\napi_base_url = \"https://proxy.example.com/github\"")
+                .unwrap();
+        assert_eq!(config.github.web_base_url(), "https://github.com");
+    }
+
+    #[test]
+    fn test_github_api_base_url() {
+        let config = Config::default();
+        assert_eq!(
+            config.github.api_url("/notifications"),
+            "https://api.github.com/notifications"
+        );
+
+        let config: Config =
+            toml::from_str("[github]\napi_base_url = \"https://ghe.example.com/api/v3/\"").unwrap();
+        assert!(validate_config(&config).is_ok());
+        assert_eq!(
+            config.github.api_url("/notifications"),
+            "https://ghe.example.com/api/v3/notifications"
+        );
+
+        let config: Config =
+            toml::from_str("[github]\napi_base_url = \"ghe.example.com\"").unwrap();
+        assert!(validate_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_replace_pat_preserves_other_settings() {
+        let contents = "# My settings\npoll_interval_sec = 60\npat = \"old\"\n\n[notification]\nmode = \"digest\"\n";
+        assert_eq!(
+            replace_pat(contents, "ghp_new"),
+            "# My settings\npoll_interval_sec = 60\npat = \"ghp_new\"\n\n[notification]\nmode = \"digest\"\n"
+        );
+
+        // pat がない場合は先頭に追加（テーブル内の同名のキーは置き換えない）
+        let contents = "poll_interval_sec = 60\n[other]\npat = \"keep\"\n";
+        assert_eq!(
+            replace_pat(contents, "ghp_new"),
+            "pat = \"ghp_new\"\npoll_interval_sec = 60\n[other]\npat = \"keep\"\n"
+        );
+    }
+
+    #[test]
+    fn test_load_config_from() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("alternate.toml");
+        fs::write(&config_path, "poll_interval_sec = 90\n").unwrap();
+        assert_eq!(
+            load_config_from(&config_path).unwrap().poll_interval_sec,
+            90
+        );
+
+        // 明示的に指定されたファイルがない場合はデフォルト設定を使わずにエラー
+        let missing = dir.path().join("missing.toml");
+        let err = load_config_from(&missing).unwrap_err();
+        assert!(err.to_string().contains("does not exist"));
+
+        fs::write(&config_path, "poll_interval_sec = \"often\"\n").unwrap();
+        assert!(load_config_from(&config_path).is_err());
+    }
+
+    #[test]
+    fn test_save_pat_preserves_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.toml");
+        let state_path = dir.path().join("state.json");
+        fs::write(&config_path, "pat = \"revoked\"\npoll_interval_sec = 45\n").unwrap();
+        fs::write(
+            &state_path,
+            "{\"last_checked_at\":\"2023-01-02T00:00:00Z\"}",
+        )
+        .unwrap();
+
+        save_pat_to(&config_path, "ghp_new").unwrap();
+
+        let config: Config = toml::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(config.pat.as_deref(), Some("ghp_new"));
+        assert_eq!(config.poll_interval_sec, 45);
+        assert_eq!(
+            fs::read_to_string(&state_path).unwrap(),
+            "{\"last_checked_at\":\"2023-01-02T00:00:00Z\"}"
+        );
+    }
+
+    #[test]
+    fn test_validate_display_timezone() {
+        let config: Config = toml::from_str("[display]\ntimezone = \"+09:00\"").unwrap();
+        assert!(validate_config(&config).is_ok());
+
+        let config: Config = toml::from_str("[display]\ntimezone = \"Mars/Base\"").unwrap();
+        assert!(validate_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_config_warnings() {
+        let config: Config = toml::from_str(
+            "poll_interval_sec = 30\n[[repository_overrides]]\nrepository = \"org/api\"\npoll_interval_sec = 60",
+        )
+        .unwrap();
+        let warnings = config_warnings(&config);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].starts_with("pat is not set"));
+        assert!(warnings[1].contains("'org/api' is ignored"));
+
+        let config: Config = toml::from_str("pat = \"ghp_token\"").unwrap();
+        assert!(config_warnings(&config).is_empty());
+    }
+
+    #[test]
+    fn test_validate_reason_patterns() {
+        let config: Config =
+            toml::from_str("[notification_filters]\ninclude_reason_patterns = [\".*_mention\"]")
+                .unwrap();
+        assert!(validate_config(&config).is_ok());
+
+        let config: Config =
+            toml::from_str("[notification_filters]\nexclude_reason_patterns = [\"(unclosed\"]")
+                .unwrap();
+        let error = validate_config(&config).unwrap_err().to_string();
+        assert!(error.contains("exclude_reason_patterns"));
+    }
+
+    #[test]
+    fn test_notifications_url_query() {
+        let mut github = GitHubConfig::default();
+        assert_eq!(
+            github.notifications_url("/notifications"),
+            "https://api.github.com/notifications"
+        );
+
+        github.show_read_notifications = true;
+        github.participating_only = true;
+        assert_eq!(
+            github.notifications_url("/repos/user/repo/notifications"),
+            "https://api.github.com/repos/user/repo/notifications?all=true&participating=true"
+        );
+    }
+
+    #[test]
+    fn test_validate_polling_max_concurrent() {
+        assert_eq!(Config::default().polling.max_concurrent, 4);
+
+        let config: Config = toml::from_str("[polling]\nmax_concurrent = 0").unwrap();
+        let error = validate_config(&config).unwrap_err().to_string();
+        assert!(error.contains("polling.max_concurrent"));
+    }
+
+    #[test]
+    fn test_validate_github_ca_bundle() {
+        let config: Config =
+            toml::from_str("[github]\nca_bundle = \"/nonexistent/ca.pem\"").unwrap();
+        assert!(validate_config(&config).is_err());
+        assert!(!config.github.danger_accept_invalid_certs);
+    }
+
+    #[test]
+    fn test_validate_notification_sound_file() {
+        let config: Config =
+            toml::from_str("[notification]\nsound_file = \"/nonexistent/alert.oga\"").unwrap();
+        let error = validate_config(&config).unwrap_err().to_string();
+        assert!(error.contains("notification.sound_file"));
+    }
+
+    #[test]
+    fn test_validate_notification_slack() {
+        let config: Config = toml::from_str("[notification.slack]\nenabled = true").unwrap();
+        let error = validate_config(&config).unwrap_err().to_string();
+        assert!(error.contains("notification.slack.webhook_url"));
+
+        let config: Config = toml::from_str(
+            "[notification.slack]\nenabled = true\nwebhook_url = \"https://hooks.slack.com/services/T/B/X\"",
+        )
+        .unwrap();
+        assert!(validate_config(&config).is_ok());
+        assert!(!config.notification.slack.replace_desktop);
+    }
+
+    #[test]
+    fn test_validate_notification_templates() {
+        let config: Config =
+            toml::from_str("[notification]\ntitle_template = \"{repo}: {title}\"").unwrap();
+        assert!(validate_config(&config).is_ok());
+
+        let config: Config =
+            toml::from_str("[notification]\nbody_template = \"{title} by {author}\"").unwrap();
+        let error = validate_config(&config).unwrap_err().to_string();
+        assert!(error.contains("notification.body_template"));
+        assert!(error.contains("{author}"));
+    }
+
+    #[test]
+    fn test_dedupe_window_sec_alias() {
+        let config: Config = toml::from_str("[notification]\ndedupe_window_sec = 120").unwrap();
+        assert_eq!(config.notification.thread_cooldown_sec, 120);
+    }
+
+    #[test]
+    fn test_sound_on_priority_alias() {
+        assert_eq!(Config::default().notification.sound_min_priority, None);
+
+        let config: Config =
+            toml::from_str("[notification]\nsound_on_priority = \"high\"").unwrap();
+        assert_eq!(
+            config.notification.sound_min_priority,
+            Some(NotificationPriority::High)
+        );
+    }
+
     #[tokio::test]
     async fn test_load_default_config() {
         // 存在しないファイルパスでテスト
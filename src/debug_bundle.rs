@@ -0,0 +1,294 @@
+use crate::Config;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// バンドルに含めるログの最大行数（最新の行から）
+const MAX_LOG_LINES: usize = 1000;
+
+/// トークンをマスクするときの置換文字列
+const MASK: &str = "********";
+
+/// GitHub のトークンの接頭辞（設定ファイル以外に紛れ込んだトークンもマスクする）
+const TOKEN_PREFIXES: [&str; 6] = ["github_pat_", "ghp_", "gho_", "ghu_", "ghs_", "ghr_"];
+
+/// デバッグバンドルに含める 1 ファイル分の内容
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleEntry {
+    pub name: String,
+    pub contents: String,
+}
+
+impl BundleEntry {
+    fn new(name: impl Into<String>, contents: impl Into<String>) -> Self {
+        BundleEntry {
+            name: name.into(),
+            contents: contents.into(),
+        }
+    }
+}
+
+/// 不具合報告用のデバッグバンドルの内容を収集
+///
+/// トークンは常にマスクされ、`include_data` が true の場合のみ状態ファイルと
+/// 保存された通知の生の JSON（通知のタイトルなどを含む）を含める
+pub fn collect_entries(
+    config: &Config,
+    include_data: bool,
+) -> Result<Vec<BundleEntry>, Box<dyn std::error::Error + Send + Sync>> {
+    let mut entries = vec![
+        BundleEntry::new("version.txt", get_version_info()),
+        BundleEntry::new("system.txt", get_system_info()),
+        BundleEntry::new("config.toml", masked_config(config)?),
+        BundleEntry::new("state_summary.txt", state_summary()),
+    ];
+
+    if let Some(log_path) = latest_log_file(config) {
+        match read_log_tail(&log_path, MAX_LOG_LINES) {
+            Ok(log) => entries.push(BundleEntry::new("logs/gh-notifier.log", log)),
+            Err(e) => tracing::warn!("Failed to read log file {}: {}", log_path.display(), e),
+        }
+    }
+
+    if include_data {
+        let state_path = crate::paths::state_file_path();
+        if let Ok(state) = fs::read_to_string(&state_path) {
+            entries.push(BundleEntry::new("data/state.json", state));
+        }
+
+        let raw_dir = crate::raw_payload::default_dir();
+        if let Ok(dir) = fs::read_dir(&raw_dir) {
+            for path in dir.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+                let (Some(file_name), Ok(contents)) = (path.file_name(), fs::read_to_string(&path))
+                else {
+                    continue;
+                };
+                entries.push(BundleEntry::new(
+                    format!("data/raw_payloads/{}", file_name.to_string_lossy()),
+                    contents,
+                ));
+            }
+        }
+    }
+
+    // 設定以外（ログなど）に含まれるトークンもマスクする
+    let secrets: Vec<&str> = config
+        .pat
+        .iter()
+        .chain(config.webhook.secret.iter())
+        .chain(config.notification.slack.webhook_url.iter())
+        .map(|secret| secret.trim())
+        .collect();
+    for entry in &mut entries {
+        entry.contents = mask_secrets(&entry.contents, &secrets);
+    }
+
+    Ok(entries)
+}
+
+/// 収集した内容を zip ファイルとして書き出す
+pub fn write_bundle(
+    path: &Path,
+    entries: &[BundleEntry],
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let file = fs::File::create(path)?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    for entry in entries {
+        zip.start_file(entry.name.as_str(), options)?;
+        zip.write_all(entry.contents.as_bytes())?;
+    }
+    zip.finish()?;
+    Ok(())
+}
+
+/// gh-notifier のバージョンと使用中のプロファイル
+pub fn get_version_info() -> String {
+    format!(
+        "gh-notifier {}\nprofile: {}\n",
+        env!("CARGO_PKG_VERSION"),
+        crate::paths::current_profile().unwrap_or("(default)")
+    )
+}
+
+/// OS とアーキテクチャの情報
+pub fn get_system_info() -> String {
+    format!(
+        "os: {}\nfamily: {}\narch: {}\n",
+        std::env::consts::OS,
+        std::env::consts::FAMILY,
+        std::env::consts::ARCH
+    )
+}
+
+/// トークンをマスクした有効な設定
+pub(crate) fn masked_config(
+    config: &Config,
+) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    let mut config = config.clone();
+    if config.pat.is_some() {
+        config.pat = Some(MASK.to_string());
+    }
+    if config.webhook.secret.is_some() {
+        config.webhook.secret = Some(MASK.to_string());
+    }
+    // Incoming Webhook の URL にはそれ自体に認証情報が含まれる
+    if config.notification.slack.webhook_url.is_some() {
+        config.notification.slack.webhook_url = Some(MASK.to_string());
+    }
+    Ok(toml::to_string_pretty(&config)?)
+}
+
+/// 状態ファイルの概要（件数のみで通知の内容は含まない）
+fn state_summary() -> String {
+    let state_path = crate::paths::state_file_path();
+    let mut lines = vec![format!("state file: {}", state_path.display())];
+
+    match crate::StateManager::new_with_path(state_path) {
+        Ok(state_manager) => {
+            let state = &state_manager.state;
+            lines.push(format!(
+                "last_checked_at: {}",
+                state.last_checked_at.as_deref().unwrap_or("(never)")
+            ));
+            lines.push(format!(
+                "tracked threads: {}",
+                state.thread_dispatched_at.len()
+            ));
+            lines.push(format!(
+                "cumulative notifications: {}",
+                state.counters.total
+            ));
+        }
+        Err(e) => lines.push(format!("failed to load state: {}", e)),
+    }
+
+    let raw_payloads = fs::read_dir(crate::raw_payload::default_dir())
+        .map(|dir| dir.filter_map(|entry| entry.ok()).count())
+        .unwrap_or(0);
+    lines.push(format!("stored raw payloads: {}", raw_payloads));
+
+    lines.join("\n") + "\n"
+}
+
+/// 最新のログファイルのパス（ファイルに出力していない場合は `None`）
+fn latest_log_file(config: &Config) -> Option<PathBuf> {
+    if let Some(path) = &config.log_file_path {
+        let path = PathBuf::from(path);
+        return path.exists().then_some(path);
+    }
+
+    // デフォルトのログは日付ごとにローテーションされるため最も新しいものを使用
+    let log_dir = dirs::data_local_dir()?.join("gh-notifier").join("logs");
+    fs::read_dir(log_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("gh-notifier.log"))
+        })
+        .max_by_key(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+}
+
+/// ログファイルの最後の `max_lines` 行
+fn read_log_tail(path: &Path, max_lines: usize) -> std::io::Result<String> {
+    let bytes = fs::read(path)?;
+    let content = String::from_utf8_lossy(&bytes);
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines.len().saturating_sub(max_lines);
+    Ok(lines[start..].join("\n") + "\n")
+}
+
+/// 既知のトークンと GitHub のトークンの形式に一致する文字列をマスク
+fn mask_secrets(text: &str, secrets: &[&str]) -> String {
+    let mut masked = text.to_string();
+    for secret in secrets.iter().filter(|secret| !secret.is_empty()) {
+        masked = masked.replace(secret, MASK);
+    }
+
+    let mut result = String::with_capacity(masked.len());
+    let mut rest = masked.as_str();
+    while let Some((index, prefix)) = TOKEN_PREFIXES
+        .iter()
+        .filter_map(|prefix| rest.find(prefix).map(|index| (index, prefix)))
+        .min_by_key(|(index, _)| *index)
+    {
+        result.push_str(&rest[..index]);
+        let token_len = rest[index + prefix.len()..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len() - index - prefix.len());
+        if token_len == 0 {
+            // 接頭辞だけの場合はトークンではない
+            result.push_str(prefix);
+        } else {
+            result.push_str(MASK);
+        }
+        rest = &rest[index + prefix.len() + token_len..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn test_bundle_never_contains_token() {
+        let token = "ghp_0123456789abcdefSECRET";
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("gh-notifier.log");
+        fs::write(
+            &log_path,
+            format!("INFO starting\nDEBUG Authorization: token {}\n", token),
+        )
+        .unwrap();
+
+        let config = Config {
+            pat: Some(token.to_string()),
+            log_file_path: Some(log_path.to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        let entries = collect_entries(&config, false).unwrap();
+        assert!(entries.iter().any(|entry| entry.name == "config.toml"));
+        assert!(
+            entries
+                .iter()
+                .any(|entry| entry.name == "logs/gh-notifier.log")
+        );
+        assert!(!entries.iter().any(|entry| entry.name.starts_with("data/")));
+
+        let bundle_path = dir.path().join("bundle.zip");
+        write_bundle(&bundle_path, &entries).unwrap();
+
+        let mut archive = zip::ZipArchive::new(fs::File::open(&bundle_path).unwrap()).unwrap();
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i).unwrap();
+            let mut contents = String::new();
+            file.read_to_string(&mut contents).unwrap();
+            assert!(!contents.contains(token), "token leaked in {}", file.name());
+            assert!(
+                !contents.contains("SECRET"),
+                "token leaked in {}",
+                file.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_mask_secrets() {
+        assert_eq!(
+            mask_secrets("token=abc123 other", &["abc123"]),
+            "token=******** other"
+        );
+        assert_eq!(
+            mask_secrets("a github_pat_11AAAA_bbb b ghs_xyz", &[]),
+            "a ******** b ********"
+        );
+        assert_eq!(mask_secrets("prefix ghp_ only", &[]), "prefix ghp_ only");
+    }
+}
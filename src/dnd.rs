@@ -0,0 +1,60 @@
+//! OS の「おやすみモード」（Do Not Disturb / 集中モード）の検出
+
+use crate::config::DndMode;
+
+/// 現在デスクトップ通知を抑制すべきかどうか
+pub fn should_suppress(mode: DndMode) -> bool {
+    should_suppress_with(mode, detect)
+}
+
+fn should_suppress_with(mode: DndMode, detect: impl FnOnce() -> Option<bool>) -> bool {
+    match mode {
+        DndMode::On => true,
+        DndMode::Off => false,
+        // 検出できない環境では抑制しない
+        DndMode::Auto => detect().unwrap_or(false),
+    }
+}
+
+/// OS のおやすみモードの状態を検出（検出できない場合は `None`）
+#[cfg(all(unix, not(target_os = "macos")))]
+pub fn detect() -> Option<bool> {
+    // GNOME: おやすみモード中はバナー表示が無効になる
+    let output = std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.notifications", "show-banners"])
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "false" => Some(true),
+        "true" => Some(false),
+        _ => None,
+    }
+}
+
+/// OS のおやすみモードの状態を検出（このプラットフォームでは未対応）
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+pub fn detect() -> Option<bool> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_suppress() {
+        assert!(should_suppress_with(DndMode::Auto, || Some(true)));
+        assert!(!should_suppress_with(DndMode::Auto, || Some(false)));
+        // Unsupported platforms are a no-op
+        assert!(!should_suppress_with(DndMode::Auto, || None));
+
+        // Manual overrides ignore detection
+        assert!(should_suppress_with(DndMode::On, || Some(false)));
+        assert!(!should_suppress_with(DndMode::Off, || Some(true)));
+    }
+}
@@ -0,0 +1,440 @@
+use crate::{AuthManager, Config, ConfigProvider, GitHubClient, MessageHandler, TokenInfo};
+use std::path::{Path, PathBuf};
+
+/// 診断チェックの結果の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// チェックに成功
+    Pass,
+    /// 動作に影響しない問題
+    Warn,
+    /// 動作に影響する致命的な問題
+    Fail,
+    /// 前提となるチェックが失敗したため未実行
+    Skip,
+}
+
+impl CheckStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => "FAIL",
+            CheckStatus::Skip => "SKIP",
+        }
+    }
+}
+
+/// 1つの診断チェックの結果
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// 問題を解決するためのヒント
+    pub hint: Option<String>,
+}
+
+impl CheckResult {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        CheckResult {
+            name,
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        CheckResult {
+            name,
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        CheckResult {
+            name,
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn skip(name: &'static str, reason: impl Into<String>) -> Self {
+        CheckResult {
+            name,
+            status: CheckStatus::Skip,
+            detail: reason.into(),
+            hint: None,
+        }
+    }
+}
+
+/// 診断チェックが参照する、設定ファイル以外の環境（テストで実際のキーチェーンや状態ディレクトリを使用しないため）
+pub trait DoctorEnvironment: Send + Sync {
+    /// `auth login --keychain` で保存した PAT
+    fn stored_pat(&self) -> Option<String>;
+
+    /// 状態ファイルを保存するディレクトリ
+    fn state_dir(&self) -> PathBuf;
+}
+
+/// キーチェーン（またはトークンファイル）とプロファイルの状態ディレクトリを参照する
+pub struct DefaultDoctorEnvironment;
+
+impl DoctorEnvironment for DefaultDoctorEnvironment {
+    fn stored_pat(&self) -> Option<String> {
+        crate::initialization_service::stored_pat()
+    }
+
+    fn state_dir(&self) -> PathBuf {
+        crate::paths::app_dir()
+    }
+}
+
+/// 設定、認証、API接続、状態ファイル、デスクトップ通知を順に診断する
+pub async fn run_checks(
+    config_provider: &dyn ConfigProvider,
+    environment: &dyn DoctorEnvironment,
+) -> Vec<CheckResult> {
+    let mut results = Vec::new();
+
+    // 設定ファイルの読み込みと検証
+    let config = match config_provider.load_config() {
+        Ok(config) => {
+            results.push(CheckResult::pass(
+                "Config",
+                format!("loaded ({})", crate::paths::config_file_path().display()),
+            ));
+            Some(config)
+        }
+        Err(e) => {
+            results.push(CheckResult::fail(
+                "Config",
+                format!("failed to load: {}", e),
+                format!(
+                    "Fix the syntax or invalid values in {}",
+                    crate::paths::config_file_path().display()
+                ),
+            ));
+            None
+        }
+    };
+
+    // GitHub API に関するチェック
+    results.extend(check_github(config.as_ref(), environment).await);
+
+    // 状態ファイルの書き込み権限
+    results.push(check_state_writable(&environment.state_dir()));
+
+    // デスクトップ通知
+    results.push(check_desktop_notifier());
+
+    results
+}
+
+/// トークンの有無・有効性・スコープと `/notifications` への接続を確認
+async fn check_github(
+    config: Option<&Config>,
+    environment: &dyn DoctorEnvironment,
+) -> Vec<CheckResult> {
+    const NETWORK_CHECKS: [&str; 3] = ["Token validity", "Token scopes", "Notifications API"];
+    let skip_network = |reason: &str| {
+        NETWORK_CHECKS
+            .iter()
+            .map(|name| CheckResult::skip(name, reason))
+            .collect::<Vec<_>>()
+    };
+
+    let Some(config) = config else {
+        let mut results = vec![CheckResult::skip("Token", "config could not be loaded")];
+        results.extend(skip_network("config could not be loaded"));
+        return results;
+    };
+
+    // 起動時と同じく、設定ファイルの PAT がない場合は `auth login --keychain` で保存した PAT を使用する
+    let (pat, source) = match crate::initialization_service::configured_pat(config) {
+        Some(pat) => (pat, "PAT found in config"),
+        None => match environment.stored_pat() {
+            Some(pat) => (pat, "PAT found in keychain"),
+            None => {
+                let mut results = vec![CheckResult::fail(
+                    "Token",
+                    "no PAT configured",
+                    "Run `gh-notifier auth login` or add 'pat = \"your_token_here\"' to your config file. Create a PAT at https://github.com/settings/tokens with the 'notifications' scope",
+                )];
+                results.extend(skip_network("no token available"));
+                return results;
+            }
+        },
+    };
+    let pat = pat.as_str();
+
+    let mut results = vec![CheckResult::pass("Token", source)];
+
+    let mut auth_manager = match AuthManager::new() {
+        Ok(auth_manager) => auth_manager,
+        Err(e) => {
+            results.extend(skip_network(&format!("auth manager unavailable: {}", e)));
+            return results;
+        }
+    };
+    auth_manager.token_info = Some(TokenInfo::from_pat(pat));
+    auth_manager.set_github_config(config.github.clone());
+
+    // /user でトークンの有効性を確認
+    match auth_manager.validate_token().await {
+        Ok(true) => results.push(CheckResult::pass("Token validity", "GET /user succeeded")),
+        Ok(false) => {
+            results.push(CheckResult::fail(
+                "Token validity",
+                "GitHub rejected the token",
+                "The PAT may be revoked or expired. Generate a new one at https://github.com/settings/tokens",
+            ));
+            results.extend(
+                NETWORK_CHECKS[1..]
+                    .iter()
+                    .map(|name| CheckResult::skip(name, "token is invalid")),
+            );
+            return results;
+        }
+        Err(e) => {
+            results.push(CheckResult::fail(
+                "Token validity",
+                format!("could not reach GitHub: {}", e),
+                "Check your network connection and proxy settings",
+            ));
+            results.extend(
+                NETWORK_CHECKS[1..]
+                    .iter()
+                    .map(|name| CheckResult::skip(name, "GitHub is unreachable")),
+            );
+            return results;
+        }
+    }
+
+    let mut github_client = match GitHubClient::with_config(auth_manager, &config.github) {
+        Ok(client) => client,
+        Err(e) => {
+            results.extend(
+                NETWORK_CHECKS[1..]
+                    .iter()
+                    .map(|name| CheckResult::skip(name, format!("HTTP client error: {}", e))),
+            );
+            return results;
+        }
+    };
+
+    // 必要なスコープ（notifications または repo）を確認
+    results.push(match github_client.get_token_scopes().await {
+        Ok(Some(scopes)) if scopes.iter().any(|s| s == "notifications" || s == "repo") => {
+            CheckResult::pass("Token scopes", scopes.join(", "))
+        }
+        Ok(Some(scopes)) => CheckResult::fail(
+            "Token scopes",
+            format!("missing 'notifications' scope (has: {})", scopes.join(", ")),
+            "Edit the token at https://github.com/settings/tokens and enable the 'notifications' scope",
+        ),
+        Ok(None) => CheckResult::warn(
+            "Token scopes",
+            "scopes not reported (fine-grained token?)",
+            "Make sure the token is allowed to read notifications",
+        ),
+        Err(e) => CheckResult::warn(
+            "Token scopes",
+            format!("could not be determined: {}", e),
+            "Make sure the token has the 'notifications' scope",
+        ),
+    });
+
+    // /notifications への接続
+    results.push(match github_client.get_notifications(None, None).await {
+        Ok(Some(notifications)) => CheckResult::pass(
+            "Notifications API",
+            format!("{} notifications returned", notifications.len()),
+        ),
+        Ok(None) => CheckResult::pass("Notifications API", "reachable (304 Not Modified)"),
+        Err(e) => CheckResult::fail(
+            "Notifications API",
+            format!("request failed: {}", e),
+            "Make sure the token has the 'notifications' scope and GitHub is reachable",
+        ),
+    });
+
+    results
+}
+
+/// 状態ファイルのディレクトリに書き込みできるかを確認
+fn check_state_writable(dir: &Path) -> CheckResult {
+    let probe = dir.join(".doctor_write_test");
+
+    let result = std::fs::create_dir_all(dir)
+        .and_then(|_| std::fs::write(&probe, b"ok"))
+        .and_then(|_| std::fs::remove_file(&probe));
+
+    match result {
+        Ok(()) => CheckResult::pass("State directory", format!("writable ({})", dir.display())),
+        Err(e) => CheckResult::fail(
+            "State directory",
+            format!("{} is not writable: {}", dir.display(), e),
+            "Check the permissions of the directory or choose another profile with --profile",
+        ),
+    }
+}
+
+/// デスクトップ通知サーバーが利用可能かを確認
+#[cfg(all(unix, not(target_os = "macos")))]
+fn check_desktop_notifier() -> CheckResult {
+    match notify_rust::get_server_information() {
+        Ok(info) => CheckResult::pass(
+            "Desktop notifier",
+            format!("{} {} ({})", info.name, info.version, info.vendor),
+        ),
+        Err(e) => CheckResult::warn(
+            "Desktop notifier",
+            format!("notification server not available: {}", e),
+            "Make sure a notification daemon is running on the D-Bus session bus",
+        ),
+    }
+}
+
+/// デスクトップ通知サーバーが利用可能かを確認
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn check_desktop_notifier() -> CheckResult {
+    CheckResult::pass(
+        "Desktop notifier",
+        "native notification API (not probed on this platform)",
+    )
+}
+
+/// 致命的な問題があるかどうか
+pub fn has_critical_failure(results: &[CheckResult]) -> bool {
+    results.iter().any(|r| r.status == CheckStatus::Fail)
+}
+
+/// 診断結果をチェックリスト形式で出力
+pub fn print_report(results: &[CheckResult], message_handler: &dyn MessageHandler) {
+    message_handler.print("gh-notifier doctor");
+    for result in results {
+        message_handler.print(&format!(
+            "[{}] {}: {}",
+            result.status.label(),
+            result.name,
+            result.detail
+        ));
+        if let Some(hint) = &result.hint {
+            message_handler.print(&format!("       hint: {}", hint));
+        }
+    }
+
+    if has_critical_failure(results) {
+        message_handler.print("Some critical checks failed.");
+    } else {
+        message_handler.print("All critical checks passed.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockConfigProvider {
+        config: Option<Config>,
+    }
+
+    impl ConfigProvider for MockConfigProvider {
+        fn load_config(&self) -> Result<Config, Box<dyn std::error::Error>> {
+            self.config
+                .clone()
+                .ok_or_else(|| "Config loading error".into())
+        }
+    }
+
+    /// キーチェーンに PAT がなく、一時ディレクトリに状態を保存する環境
+    struct MockDoctorEnvironment {
+        state_dir: tempfile::TempDir,
+    }
+
+    impl MockDoctorEnvironment {
+        fn new() -> Self {
+            MockDoctorEnvironment {
+                state_dir: tempfile::tempdir().unwrap(),
+            }
+        }
+    }
+
+    impl DoctorEnvironment for MockDoctorEnvironment {
+        fn stored_pat(&self) -> Option<String> {
+            None
+        }
+
+        fn state_dir(&self) -> PathBuf {
+            self.state_dir.path().to_path_buf()
+        }
+    }
+
+    fn status_of(results: &[CheckResult], name: &str) -> CheckStatus {
+        results
+            .iter()
+            .find(|r| r.name == name)
+            .map(|r| r.status)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_doctor_config_error() {
+        let provider = MockConfigProvider { config: None };
+        let environment = MockDoctorEnvironment::new();
+        let results = run_checks(&provider, &environment).await;
+
+        assert_eq!(status_of(&results, "Config"), CheckStatus::Fail);
+        assert_eq!(status_of(&results, "Token"), CheckStatus::Skip);
+        assert_eq!(status_of(&results, "Notifications API"), CheckStatus::Skip);
+        assert!(has_critical_failure(&results));
+    }
+
+    #[tokio::test]
+    async fn test_doctor_missing_token() {
+        let provider = MockConfigProvider {
+            config: Some(Config::default()),
+        };
+        let environment = MockDoctorEnvironment::new();
+        let results = run_checks(&provider, &environment).await;
+
+        assert_eq!(status_of(&results, "Config"), CheckStatus::Pass);
+        assert_eq!(status_of(&results, "Token"), CheckStatus::Fail);
+        assert_eq!(status_of(&results, "Token validity"), CheckStatus::Skip);
+        assert_eq!(status_of(&results, "State directory"), CheckStatus::Pass);
+        assert!(!environment.state_dir().join(".doctor_write_test").exists());
+        assert!(has_critical_failure(&results));
+        assert!(
+            results
+                .iter()
+                .find(|r| r.name == "Token")
+                .unwrap()
+                .hint
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_check_state_writable_fails_for_unwritable_dir() {
+        // ファイルの下にはディレクトリを作成できない
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let result = check_state_writable(&file.path().join("state"));
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert!(result.hint.is_some());
+    }
+
+    #[test]
+    fn test_has_critical_failure_ignores_warnings() {
+        let results = vec![
+            CheckResult::pass("Config", "loaded"),
+            CheckResult::warn("Desktop notifier", "unavailable", "start a daemon"),
+        ];
+        assert!(!has_critical_failure(&results));
+    }
+}
@@ -10,6 +10,14 @@ pub enum AuthError {
     KeyringError(keyring::Error),
     /// General authentication error
     GeneralError(String),
+    /// The primary rate limit (requests per hour) is exhausted
+    RateLimitExceeded(String),
+    /// A secondary rate limit (too many requests in a short time) was hit;
+    /// no request should be made before `retry_after` has passed
+    SecondaryRateLimit {
+        retry_after: std::time::Duration,
+        message: String,
+    },
 }
 
 impl std::fmt::Display for AuthError {
@@ -19,6 +27,16 @@ impl std::fmt::Display for AuthError {
             AuthError::JsonError(e) => write!(f, "JSON error: {}", e),
             AuthError::KeyringError(e) => write!(f, "Keyring error: {}", e),
             AuthError::GeneralError(msg) => write!(f, "Authentication error: {}", msg),
+            AuthError::RateLimitExceeded(msg) => write!(f, "Rate limit exceeded: {}", msg),
+            AuthError::SecondaryRateLimit {
+                retry_after,
+                message,
+            } => write!(
+                f,
+                "Secondary rate limit exceeded (retry after {}s): {}",
+                retry_after.as_secs(),
+                message
+            ),
         }
     }
 }
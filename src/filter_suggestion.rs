@@ -0,0 +1,50 @@
+/// Watch しているリポジトリから `[notification_filters]` の設定例を生成
+///
+/// GitHub 側の設定を正確に再現するものではなく、フィルタ設定の出発点としての提案
+pub fn format_suggestion(watched_repositories: &[String]) -> String {
+    let mut repositories: Vec<&str> = watched_repositories.iter().map(|r| r.as_str()).collect();
+    repositories.sort_unstable();
+    repositories.dedup();
+
+    let mut lines = vec![
+        "# Suggested filter configuration based on the repositories you watch on GitHub."
+            .to_string(),
+        "# This is a best-effort suggestion, not an authoritative sync of your GitHub".to_string(),
+        "# notification settings. Review it before adding it to your config file.".to_string(),
+        "[notification_filters]".to_string(),
+    ];
+
+    if repositories.is_empty() {
+        lines.push("# No watched repositories were found.".to_string());
+        lines.push("include_repositories = []".to_string());
+    } else {
+        lines.push(format!("# {} watched repositories", repositories.len()));
+        lines.push("include_repositories = [".to_string());
+        lines.extend(repositories.iter().map(|r| format!("  \"{}\",", r)));
+        lines.push("]".to_string());
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_suggestion() {
+        let suggestion = format_suggestion(&[
+            "org/b".to_string(),
+            "org/a".to_string(),
+            "org/b".to_string(),
+        ]);
+
+        // The output is a valid config snippet
+        let config: crate::Config = toml::from_str(&suggestion).unwrap();
+        assert_eq!(
+            config.notification_filters.include_repositories,
+            vec!["org/a", "org/b"]
+        );
+        assert!(suggestion.contains("suggestion"));
+    }
+}
@@ -1,35 +1,123 @@
+use crate::config::GitHubConfig;
+use crate::raw_payload::RawPayloadStore;
 use crate::{AuthError, AuthManager, Notification};
 use reqwest::Client;
+use std::time::Duration;
 
 pub struct GitHubClient {
     client: Client,
     auth_manager: AuthManager,
+    /// 受信した通知の生の JSON の保存先（`debug.store_raw_payload` が有効な場合のみ）
+    raw_payload_store: Option<RawPayloadStore>,
+    /// 接続設定（API のベース URL の決定に使用）
+    github_config: GitHubConfig,
+    /// 最後に通知を取得したレスポンスのレート制限
+    rate_limit: Option<RateLimit>,
+    /// 最後に通知を取得できたレスポンスの `ETag`（304 Not Modified の場合は更新しない）
+    last_etag: Option<String>,
+}
+
+/// レスポンスの `X-RateLimit-*` ヘッダーから読み取ったレート制限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// 1時間あたりのリクエスト数の上限
+    pub limit: u64,
+    /// 残りのリクエスト数
+    pub remaining: u64,
+    /// 残りのリクエスト数がリセットされる時刻（UNIX 時間、秒）
+    pub reset: u64,
+}
+
+impl RateLimit {
+    /// レート制限のヘッダーがすべて揃っている場合のみ読み取る
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+        Some(RateLimit {
+            limit: header("X-RateLimit-Limit")?,
+            remaining: header("X-RateLimit-Remaining")?,
+            reset: header("X-RateLimit-Reset")?,
+        })
+    }
 }
 
 impl GitHubClient {
     pub fn new(auth_manager: AuthManager) -> Result<Self, AuthError> {
-        let client = Client::builder()
-            .user_agent(format!("gh-notifier/{}", env!("CARGO_PKG_VERSION")))
-            .build()
-            .map_err(|e| AuthError::GeneralError(format!("Failed to create HTTP client: {}", e)))?;
+        Self::with_config(auth_manager, &GitHubConfig::default())
+    }
+
+    /// 接続設定（API のベース URL、CA 証明書、証明書検証の有無）を指定してクライアントを作成
+    pub fn with_config(
+        auth_manager: AuthManager,
+        github_config: &GitHubConfig,
+    ) -> Result<Self, AuthError> {
+        let client = build_http_client(github_config)?;
 
         Ok(GitHubClient {
             client,
             auth_manager,
+            raw_payload_store: None,
+            github_config: github_config.clone(),
+            rate_limit: None,
+            last_etag: None,
         })
     }
 
+    /// 受信した通知の生の JSON の保存先を設定
+    pub fn set_raw_payload_store(&mut self, store: Option<RawPayloadStore>) {
+        self.raw_payload_store = store;
+    }
+
+    /// 最後に通知を取得したレスポンスのレート制限（ヘッダーが返されなかった場合は `None`）
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit
+    }
+
+    /// 最後に通知を取得できたレスポンスの `ETag`（次回の `If-None-Match` に使用）
+    pub fn last_etag(&self) -> Option<&str> {
+        self.last_etag.as_deref()
+    }
+
     /// `/notifications` エンドポイントから通知を取得
     /// `if_modified_since` と `etag` はオプショナルで設定可能
+    /// （`[github]` の `show_read_notifications` / `participating_only` をクエリに含める）
     pub async fn get_notifications(
         &mut self,
         if_modified_since: Option<&str>,
         etag: Option<&str>,
+    ) -> Result<Option<Vec<Notification>>, AuthError> {
+        let url = self.github_config.notifications_url("/notifications");
+        self.fetch_notifications(&url, if_modified_since, etag)
+            .await
+    }
+
+    /// 指定したリポジトリ（`owner/repo`）の通知のみを取得
+    pub async fn get_repository_notifications(
+        &mut self,
+        full_name: &str,
+        if_modified_since: Option<&str>,
+    ) -> Result<Option<Vec<Notification>>, AuthError> {
+        let url = self
+            .github_config
+            .notifications_url(&format!("/repos/{}/notifications", full_name));
+        self.fetch_notifications(&url, if_modified_since, None)
+            .await
+    }
+
+    async fn fetch_notifications(
+        &mut self,
+        url: &str,
+        if_modified_since: Option<&str>,
+        etag: Option<&str>,
     ) -> Result<Option<Vec<Notification>>, AuthError> {
         let token = self.auth_manager.get_valid_token().await?;
         let mut request_builder = self
             .client
-            .get("https://api.github.com/notifications")
+            .get(url)
             .header("Authorization", format!("token {}", token));
 
         if let Some(ims) = if_modified_since {
@@ -40,7 +128,10 @@ impl GitHubClient {
             request_builder = request_builder.header("If-None-Match", etag);
         }
 
-        let response = request_builder.send().await?;
+        let response = self.send_with_retry(request_builder).await?;
+        if let Some(rate_limit) = RateLimit::from_headers(response.headers()) {
+            self.rate_limit = Some(rate_limit);
+        }
 
         // 304 Not Modified の場合は None を返す
         if response.status() == reqwest::StatusCode::NOT_MODIFIED {
@@ -50,10 +141,58 @@ impl GitHubClient {
         // それ以外の場合は JSON をデシリアライズして返す
         let status = response.status();
         if status.is_success() {
-            let notifications: Vec<Notification> = response.json().await?;
+            self.last_etag = etag_from_headers(response.headers());
+            let mut next_url = next_page_url(response.headers());
+            let mut payloads: Vec<serde_json::Value> = response.json().await?;
+
+            // 2ページ目以降は Link ヘッダーの rel="next" をたどって取得
+            let mut pages = 1;
+            while let Some(url) = next_url.take() {
+                if pages >= self.github_config.max_notification_pages {
+                    tracing::warn!(
+                        "Stopped fetching notifications after {} pages (github.max_notification_pages)",
+                        pages
+                    );
+                    break;
+                }
+
+                let request_builder = self
+                    .client
+                    .get(&url)
+                    .header("Authorization", format!("token {}", token));
+                let response = self.send_with_retry(request_builder).await?;
+                if let Some(rate_limit) = RateLimit::from_headers(response.headers()) {
+                    self.rate_limit = Some(rate_limit);
+                }
+                let status = response.status();
+                if !status.is_success() {
+                    let text = response.text().await?;
+                    return Err(AuthError::GeneralError(format!(
+                        "Failed to get notifications page {}: {} - {}",
+                        pages + 1,
+                        status,
+                        text
+                    )));
+                }
+
+                next_url = next_page_url(response.headers());
+                let page: Vec<serde_json::Value> = response.json().await?;
+                payloads.extend(page);
+                pages += 1;
+            }
+
+            if let Some(store) = &self.raw_payload_store {
+                store_raw_payloads(store, &payloads);
+            }
+
+            let notifications = payloads
+                .into_iter()
+                .map(serde_json::from_value)
+                .collect::<Result<Vec<Notification>, _>>()?;
             Ok(Some(notifications))
         } else if status == reqwest::StatusCode::FORBIDDEN {
             // Special handling for 403 errors - could be token-related or other API restrictions
+            let headers = response.headers().clone();
             let text = response.text().await?;
             // Check if the error message contains specific indicators for token issues
             if text.contains("Bad credentials") || text.contains("Invalid token") {
@@ -61,66 +200,272 @@ impl GitHubClient {
                     "Authentication token error: {} - {}",
                     status, text
                 )))
+            } else if let Some(kind) = rate_limit_kind(status, &headers, &text) {
+                Err(rate_limit_error(kind, &headers, &text))
             } else {
-                // For other 403 errors (like rate limits, API restrictions), don't treat as authentication error
+                // For other 403 errors (like API restrictions), don't treat as authentication error
                 Err(AuthError::GeneralError(format!(
                     "API access error: {} - {}",
                     status, text
                 )))
             }
         } else {
-            let status_code = response.status();
+            let headers = response.headers().clone();
             let text = response.text().await?;
+            if let Some(kind) = rate_limit_kind(status, &headers, &text) {
+                return Err(rate_limit_error(kind, &headers, &text));
+            }
             Err(AuthError::GeneralError(format!(
                 "Failed to get notifications: {} - {}",
-                status_code, text
+                status, text
             )))
         }
     }
 
+    /// リクエストを送信し、一時的なエラーの場合は `[github]` の `retry_count` 回まで再試行する
+    ///
+    /// 再試行の間隔は指数バックオフ（`retry_base_sec` * 2^回数、上限 `retry_max_sec`）にランダムな揺らぎを加えたもので、
+    /// `Retry-After` ヘッダーがある場合はその秒数待つ
+    async fn send_with_retry(
+        &self,
+        request_builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let mut attempt = 0;
+        loop {
+            let Some(retry_builder) = request_builder.try_clone() else {
+                return request_builder.send().await;
+            };
+            let last_attempt = attempt >= self.github_config.retry_count;
+            let result = retry_builder.send().await;
+
+            let retry_after = match &result {
+                Ok(response) if !last_attempt && is_retryable_status(response) => {
+                    retry_after_from_headers(response.headers())
+                }
+                Err(e) if !last_attempt && !e.is_builder() => None,
+                _ => return result,
+            };
+
+            let delay = retry_delay(
+                attempt,
+                &self.github_config,
+                retry_after,
+                rand::random::<f64>(),
+            );
+            match &result {
+                Ok(response) => tracing::warn!(
+                    "GitHub API returned {}, retrying in {:?} (attempt {}/{})",
+                    response.status(),
+                    delay,
+                    attempt + 1,
+                    self.github_config.retry_count
+                ),
+                Err(e) => tracing::warn!(
+                    "GitHub API request failed: {}, retrying in {:?} (attempt {}/{})",
+                    e,
+                    delay,
+                    attempt + 1,
+                    self.github_config.retry_count
+                ),
+            }
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// 認証ユーザーが Watch しているリポジトリのフルネーム（`owner/repo`）の一覧を取得
+    pub async fn get_watched_repositories(&mut self) -> Result<Vec<String>, AuthError> {
+        #[derive(serde::Deserialize)]
+        struct Repository {
+            full_name: String,
+        }
+
+        let repositories: Vec<Repository> = self
+            .get_all_pages("/user/subscriptions", "watched repositories")
+            .await?;
+        Ok(repositories.into_iter().map(|r| r.full_name).collect())
+    }
+
+    /// 認証ユーザーが所属する組織の名前の一覧を取得
+    /// （非公開のメンバーシップを含めるにはトークンに `read:org` スコープが必要）
+    pub async fn get_user_organizations(&mut self) -> Result<Vec<String>, AuthError> {
+        #[derive(serde::Deserialize)]
+        struct Organization {
+            login: String,
+        }
+
+        let organizations: Vec<Organization> =
+            self.get_all_pages("/user/orgs", "organizations").await?;
+        Ok(organizations.into_iter().map(|o| o.login).collect())
+    }
+
+    /// `page` クエリでページをたどって一覧の API の結果をすべて取得（最大 `MAX_PAGES` ページ）
+    async fn get_all_pages<T: serde::de::DeserializeOwned>(
+        &mut self,
+        path: &str,
+        what: &str,
+    ) -> Result<Vec<T>, AuthError> {
+        const PER_PAGE: usize = 100;
+        const MAX_PAGES: usize = 10;
+
+        let token = self.auth_manager.get_valid_token().await?;
+        let url = self.github_config.api_url(path);
+        let mut items = Vec::new();
+
+        for page in 1..=MAX_PAGES {
+            let response = self
+                .client
+                .get(&url)
+                .query(&[("per_page", PER_PAGE), ("page", page)])
+                .header("Authorization", format!("token {}", token))
+                .send()
+                .await?;
+
+            let status = response.status();
+            if !status.is_success() {
+                let text = response.text().await?;
+                return Err(AuthError::GeneralError(format!(
+                    "Failed to get {}: {} - {}",
+                    what, status, text
+                )));
+            }
+
+            let page_items: Vec<T> = response.json().await?;
+            let is_last_page = page_items.len() < PER_PAGE;
+            items.extend(page_items);
+            if is_last_page {
+                break;
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// 通知のサブジェクト（PR・Issue）の API の URL からラベル名の一覧を取得
+    pub async fn get_subject_labels(
+        &mut self,
+        subject_url: &str,
+    ) -> Result<Vec<String>, AuthError> {
+        let token = self.auth_manager.get_valid_token().await?;
+        fetch_subject_labels(&self.client, &token, subject_url).await
+    }
+
+    /// 複数のサブジェクトのラベルを、同時に最大 `max_concurrent` 件のリクエストで取得
+    ///
+    /// 結果は `subject_urls` と同じ順で返す
+    pub async fn get_subjects_labels(
+        &mut self,
+        subject_urls: &[String],
+        max_concurrent: usize,
+    ) -> Vec<Result<Vec<String>, AuthError>> {
+        let token = match self.auth_manager.get_valid_token().await {
+            Ok(token) => token,
+            Err(e) => {
+                let message = e.to_string();
+                return subject_urls
+                    .iter()
+                    .map(|_| Err(AuthError::GeneralError(message.clone())))
+                    .collect();
+            }
+        };
+
+        let client = self.client.clone();
+        crate::concurrency::run_bounded(subject_urls.to_vec(), max_concurrent, |url| {
+            let (client, token) = (client.clone(), token.clone());
+            async move { fetch_subject_labels(&client, &token, &url).await }
+        })
+        .await
+        .into_iter()
+        .map(|result| {
+            result.unwrap_or_else(|| {
+                Err(AuthError::GeneralError(
+                    "Label lookup task failed".to_string(),
+                ))
+            })
+        })
+        .collect()
+    }
+
     /// 通知を既読にする
     pub async fn mark_notification_as_read(
         &mut self,
         notification_id: &str,
     ) -> Result<(), AuthError> {
         let token = self.auth_manager.get_valid_token().await?;
-        let url = format!(
-            "https://api.github.com/notifications/threads/{}",
-            notification_id
-        );
+        let url = self
+            .github_config
+            .api_url(&format!("/notifications/threads/{}", notification_id));
+        mark_thread_as_read(&self.client, &token, &url).await
+    }
+
+    /// 複数の通知を、同時に最大 `max_concurrent` 件のリクエストで既読にする
+    ///
+    /// 結果は `notification_ids` と同じ順で返す
+    pub async fn mark_notifications_as_read(
+        &mut self,
+        notification_ids: &[String],
+        max_concurrent: usize,
+    ) -> Vec<Result<(), AuthError>> {
+        let token = match self.auth_manager.get_valid_token().await {
+            Ok(token) => token,
+            Err(e) => {
+                let message = e.to_string();
+                return notification_ids
+                    .iter()
+                    .map(|_| Err(AuthError::GeneralError(message.clone())))
+                    .collect();
+            }
+        };
+
+        let urls: Vec<String> = notification_ids
+            .iter()
+            .map(|id| {
+                self.github_config
+                    .api_url(&format!("/notifications/threads/{}", id))
+            })
+            .collect();
+        let client = self.client.clone();
+        crate::concurrency::run_bounded(urls, max_concurrent, |url| {
+            let (client, token) = (client.clone(), token.clone());
+            async move { mark_thread_as_read(&client, &token, &url).await }
+        })
+        .await
+        .into_iter()
+        .map(|result| {
+            result.unwrap_or_else(|| {
+                Err(AuthError::GeneralError(
+                    "Mark-as-read task failed".to_string(),
+                ))
+            })
+        })
+        .collect()
+    }
+
+    /// 認証済みユーザーのトークンに付与されているスコープを取得
+    /// Fine-grained PAT など `X-OAuth-Scopes` ヘッダーが返されない場合は `None`
+    pub async fn get_token_scopes(&mut self) -> Result<Option<Vec<String>>, AuthError> {
+        let token = self.auth_manager.get_valid_token().await?;
         let response = self
             .client
-            .patch(&url)
+            .get(self.github_config.api_url("/user"))
             .header("Authorization", format!("token {}", token))
             .send()
             .await?;
 
         let status = response.status();
-        if status.is_success() {
-            Ok(())
-        } else if status == reqwest::StatusCode::FORBIDDEN {
-            // Special handling for 403 errors - could be token-related or other API restrictions
-            let text = response.text().await?;
-            // Check if the error message contains specific indicators for token issues
-            if text.contains("Bad credentials") || text.contains("Invalid token") {
-                Err(AuthError::GeneralError(format!(
-                    "Authentication token error: {} - {}",
-                    status, text
-                )))
-            } else {
-                // For other 403 errors (like rate limits, API restrictions), don't treat as authentication error
-                Err(AuthError::GeneralError(format!(
-                    "API access error: {} - {}",
-                    status, text
-                )))
-            }
-        } else {
+        if !status.is_success() {
             let text = response.text().await?;
-            Err(AuthError::GeneralError(format!(
-                "Failed to mark notification as read: {} - {}",
+            return Err(AuthError::GeneralError(format!(
+                "Failed to get authenticated user: {} - {}",
                 status, text
-            )))
+            )));
         }
+
+        Ok(response
+            .headers()
+            .get("X-OAuth-Scopes")
+            .and_then(|v| v.to_str().ok())
+            .map(parse_scopes_header))
     }
 
     /// Get a mutable reference to the auth manager to allow external operations like token saving
@@ -134,6 +479,279 @@ impl GitHubClient {
     }
 }
 
+/// 再試行すべきレスポンスかどうか（5xx、429、`Retry-After` 付きの 403 = セカンダリレート制限）
+fn is_retryable_status(response: &reqwest::Response) -> bool {
+    let status = response.status();
+    status.is_server_error()
+        || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || (status == reqwest::StatusCode::FORBIDDEN
+            && response
+                .headers()
+                .contains_key(reqwest::header::RETRY_AFTER))
+}
+
+/// セカンダリレート制限で `Retry-After` ヘッダーがない場合に待機する時間
+/// （GitHub のドキュメントでは少なくとも1分待つことが推奨されている）
+pub const SECONDARY_RATE_LIMIT_DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+/// GitHub のレート制限の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RateLimitKind {
+    /// 1時間あたりのリクエスト数の上限（`X-RateLimit-Reset` まで回復しない）
+    Primary,
+    /// 短時間のリクエストの集中などによるセカンダリレート制限（`Retry-After` に従って待機する）
+    Secondary,
+}
+
+/// エラーレスポンスがどちらのレート制限によるものかを判定（レート制限でない場合は `None`）
+fn rate_limit_kind(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+    body: &str,
+) -> Option<RateLimitKind> {
+    if status != reqwest::StatusCode::FORBIDDEN && status != reqwest::StatusCode::TOO_MANY_REQUESTS
+    {
+        return None;
+    }
+
+    if body.to_lowercase().contains("secondary rate limit") {
+        return Some(RateLimitKind::Secondary);
+    }
+    let exhausted = headers
+        .get("X-RateLimit-Remaining")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|remaining| remaining.trim() == "0");
+    if body.contains("API rate limit exceeded") || exhausted {
+        return Some(RateLimitKind::Primary);
+    }
+    // メッセージがなくても Retry-After 付きの 403 / 429 はセカンダリレート制限
+    headers
+        .contains_key(reqwest::header::RETRY_AFTER)
+        .then_some(RateLimitKind::Secondary)
+}
+
+/// レート制限のエラー（セカンダリレート制限は `Retry-After` の秒数、ない場合はデフォルトの待機時間を含める）
+fn rate_limit_error(
+    kind: RateLimitKind,
+    headers: &reqwest::header::HeaderMap,
+    body: &str,
+) -> AuthError {
+    match kind {
+        RateLimitKind::Primary => AuthError::RateLimitExceeded(body.to_string()),
+        RateLimitKind::Secondary => AuthError::SecondaryRateLimit {
+            retry_after: retry_after_from_headers(headers)
+                .unwrap_or(SECONDARY_RATE_LIMIT_DEFAULT_WAIT),
+            message: body.to_string(),
+        },
+    }
+}
+
+/// `Retry-After` ヘッダーの秒数
+fn retry_after_from_headers(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// `attempt` 回目（0 始まり）の再試行までの待機時間
+///
+/// `Retry-After` がある場合はそれに従い、ない場合は `retry_base_sec` * 2^attempt（上限 `retry_max_sec`）の
+/// 半分から全体までの間で `jitter`（0.0〜1.0）に応じて揺らがせる
+fn retry_delay(
+    attempt: u32,
+    github_config: &GitHubConfig,
+    retry_after: Option<Duration>,
+    jitter: f64,
+) -> Duration {
+    if let Some(retry_after) = retry_after {
+        return retry_after;
+    }
+
+    let max = Duration::from_secs(github_config.retry_max_sec);
+    let backoff = Duration::from_secs(github_config.retry_base_sec)
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(max);
+    backoff.mul_f64(0.5 + 0.5 * jitter.clamp(0.0, 1.0))
+}
+
+/// `Link` ヘッダーから次のページ（`rel="next"`）の URL を取得
+fn next_page_url(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let link = headers.get(reqwest::header::LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        params
+            .split(';')
+            .any(|param| param.trim() == r#"rel="next""#)
+            .then(|| {
+                url.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+    })
+}
+
+/// 通知のスレッドの API の URL に PATCH を送信して既読にする
+async fn mark_thread_as_read(
+    client: &reqwest::Client,
+    token: &str,
+    url: &str,
+) -> Result<(), AuthError> {
+    let response = client
+        .patch(url)
+        .header("Authorization", format!("token {}", token))
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else if status == reqwest::StatusCode::FORBIDDEN {
+        // Special handling for 403 errors - could be token-related or other API restrictions
+        let headers = response.headers().clone();
+        let text = response.text().await?;
+        // Check if the error message contains specific indicators for token issues
+        if text.contains("Bad credentials") || text.contains("Invalid token") {
+            Err(AuthError::GeneralError(format!(
+                "Authentication token error: {} - {}",
+                status, text
+            )))
+        } else if let Some(kind) = rate_limit_kind(status, &headers, &text) {
+            Err(rate_limit_error(kind, &headers, &text))
+        } else {
+            // For other 403 errors (like API restrictions), don't treat as authentication error
+            Err(AuthError::GeneralError(format!(
+                "API access error: {} - {}",
+                status, text
+            )))
+        }
+    } else {
+        let headers = response.headers().clone();
+        let text = response.text().await?;
+        if let Some(kind) = rate_limit_kind(status, &headers, &text) {
+            return Err(rate_limit_error(kind, &headers, &text));
+        }
+        Err(AuthError::GeneralError(format!(
+            "Failed to mark notification as read: {} - {}",
+            status, text
+        )))
+    }
+}
+
+/// サブジェクト（PR・Issue）の API の URL からラベル名の一覧を取得
+async fn fetch_subject_labels(
+    client: &reqwest::Client,
+    token: &str,
+    subject_url: &str,
+) -> Result<Vec<String>, AuthError> {
+    #[derive(serde::Deserialize)]
+    struct Label {
+        name: String,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Subject {
+        #[serde(default)]
+        labels: Vec<Label>,
+    }
+
+    let response = client
+        .get(subject_url)
+        .header("Authorization", format!("token {}", token))
+        .send()
+        .await?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await?;
+        return Err(AuthError::GeneralError(format!(
+            "Failed to get labels for {}: {} - {}",
+            subject_url, status, text
+        )));
+    }
+
+    let subject: Subject = response.json().await?;
+    Ok(subject.labels.into_iter().map(|l| l.name).collect())
+}
+
+/// GitHub API 用の HTTP クライアントを作成（`[github]` の TLS 設定を反映）
+pub(crate) fn build_http_client(github_config: &GitHubConfig) -> Result<Client, AuthError> {
+    if github_config.danger_accept_invalid_certs {
+        tracing::warn!(
+            "TLS certificate verification is disabled (github.danger_accept_invalid_certs)"
+        );
+    }
+
+    http_client_builder(github_config)?
+        .build()
+        .map_err(|e| AuthError::GeneralError(format!("Failed to create HTTP client: {}", e)))
+}
+
+/// `[github]` の TLS 設定（`ca_bundle`・`danger_accept_invalid_certs`）を反映した HTTP クライアントのビルダー
+///
+/// Webhook・Slack への送信にも同じ TLS 設定を使用する
+pub(crate) fn http_client_builder(
+    github_config: &GitHubConfig,
+) -> Result<reqwest::ClientBuilder, AuthError> {
+    let mut builder =
+        Client::builder().user_agent(format!("gh-notifier/{}", env!("CARGO_PKG_VERSION")));
+
+    if let Some(ca_bundle) = &github_config.ca_bundle {
+        let pem = std::fs::read(ca_bundle).map_err(|e| {
+            AuthError::GeneralError(format!("Failed to read CA bundle {}: {}", ca_bundle, e))
+        })?;
+        let certificates = reqwest::Certificate::from_pem_bundle(&pem).map_err(|e| {
+            AuthError::GeneralError(format!("Invalid CA bundle {}: {}", ca_bundle, e))
+        })?;
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    if github_config.danger_accept_invalid_certs {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    Ok(builder)
+}
+
+/// 通知の生の JSON を通知 ID ごとに保存（失敗してもポーリングは継続）
+fn store_raw_payloads(store: &RawPayloadStore, payloads: &[serde_json::Value]) {
+    for payload in payloads {
+        let Some(id) = payload.get("id").and_then(|id| id.as_str()) else {
+            continue;
+        };
+        if let Err(e) = store.save(id, payload) {
+            tracing::warn!("Failed to store raw payload for notification {}: {}", id, e);
+        }
+    }
+    if let Err(e) = store.prune() {
+        tracing::warn!("Failed to prune raw payloads: {}", e);
+    }
+}
+
+/// レスポンスの `ETag` ヘッダーの値
+fn etag_from_headers(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+/// `X-OAuth-Scopes` ヘッダーの値（カンマ区切り）をスコープのリストに変換
+fn parse_scopes_header(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,6 +782,361 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_scopes_header() {
+        assert_eq!(
+            parse_scopes_header("notifications, repo,read:org"),
+            vec!["notifications", "repo", "read:org"]
+        );
+        assert!(parse_scopes_header("").is_empty());
+    }
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", HeaderValue::from_static("5000"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("42"));
+        headers.insert("X-RateLimit-Reset", HeaderValue::from_static("1700000000"));
+        assert_eq!(
+            RateLimit::from_headers(&headers),
+            Some(RateLimit {
+                limit: 5000,
+                remaining: 42,
+                reset: 1_700_000_000,
+            })
+        );
+
+        // ヘッダーが欠けている場合は読み取らない
+        headers.remove("X-RateLimit-Reset");
+        assert_eq!(RateLimit::from_headers(&headers), None);
+    }
+
+    #[test]
+    fn test_etag_from_headers() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(etag_from_headers(&headers), None);
+        headers.insert("ETag", HeaderValue::from_static("W/\"abc123\""));
+        assert_eq!(etag_from_headers(&headers).as_deref(), Some("W/\"abc123\""));
+    }
+
+    #[test]
+    fn test_next_page_url() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(next_page_url(&headers), None);
+        headers.insert(
+            "Link",
+            HeaderValue::from_static(
+                r#"<https://api.github.com/notifications?page=2>; rel="next", <https://api.github.com/notifications?page=5>; rel="last""#,
+            ),
+        );
+        assert_eq!(
+            next_page_url(&headers).as_deref(),
+            Some("https://api.github.com/notifications?page=2")
+        );
+
+        // 最後のページには rel="next" がない
+        headers.insert(
+            "Link",
+            HeaderValue::from_static(
+                r#"<https://api.github.com/notifications?page=1>; rel="prev""#,
+            ),
+        );
+        assert_eq!(next_page_url(&headers), None);
+    }
+
+    /// リクエストのパスから応答（ステータス行とヘッダー、本文）を返すモックサーバーを起動
+    async fn spawn_mock_server(
+        respond: fn(&str, std::net::SocketAddr) -> (String, String, String),
+    ) -> std::net::SocketAddr {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut buffer = [0u8; 2048];
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    let request = String::from_utf8_lossy(&buffer[..read]);
+                    let path = request.split_whitespace().nth(1).unwrap().to_string();
+                    let (status, headers, body) = respond(&path, address);
+                    let response = format!(
+                        "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        headers,
+                        body.len(),
+                        body
+                    );
+                    socket.write_all(response.as_bytes()).await.unwrap();
+                });
+            }
+        });
+        address
+    }
+
+    fn notification_json(id: &str) -> String {
+        format!(
+            r#"{{"id":"{id}","unread":true,"reason":"mention","updated_at":"2023-01-01T00:00:00Z","last_read_at":null,"subject":{{"title":"Issue {id}","url":null,"latest_comment_url":null,"type":"Issue"}},"repository":{{"id":1,"node_id":"R_1","name":"repo","full_name":"user/repo","private":false}},"url":"https://api.github.com/notifications/threads/{id}","subscription_url":"https://api.github.com/notifications/threads/{id}/subscription"}}"#
+        )
+    }
+
+    /// 3ページの通知の一覧（`page` クエリでページを指定）を返す
+    fn paginated_notifications(
+        path: &str,
+        address: std::net::SocketAddr,
+    ) -> (String, String, String) {
+        let page: usize = path
+            .split("page=")
+            .nth(1)
+            .and_then(|page| page.parse().ok())
+            .unwrap_or(1);
+        let link = if page < 3 {
+            format!(
+                "Link: <http://{}/notifications?page={}>; rel=\"next\"\r\n",
+                address,
+                page + 1
+            )
+        } else {
+            String::new()
+        };
+        let items: Vec<String> = (1..=2)
+            .map(|i| notification_json(&format!("{}-{}", page, i)))
+            .collect();
+        (
+            "200 OK".to_string(),
+            format!(
+                "Content-Type: application/json\r\nETag: \"page{}\"\r\n{}",
+                page, link
+            ),
+            format!("[{}]", items.join(",")),
+        )
+    }
+
+    fn client_for(address: std::net::SocketAddr, max_notification_pages: usize) -> GitHubClient {
+        let mut auth_manager = AuthManager::new().unwrap();
+        auth_manager.token_info = Some(crate::TokenInfo::from_pat("ghp_test"));
+        let github_config = crate::config::GitHubConfig {
+            api_base_url: Some(format!("http://{}", address)),
+            max_notification_pages,
+            ..Default::default()
+        };
+        GitHubClient::with_config(auth_manager, &github_config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_notifications_follows_pagination() {
+        let address = spawn_mock_server(paginated_notifications).await;
+
+        let mut github_client = client_for(address, 10);
+        let notifications = github_client
+            .get_notifications(None, None)
+            .await
+            .unwrap()
+            .unwrap();
+        let ids: Vec<&str> = notifications.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["1-1", "1-2", "2-1", "2-2", "3-1", "3-2"]);
+        // ETag は1ページ目のもの
+        assert_eq!(github_client.last_etag(), Some("\"page1\""));
+
+        // 上限のページ数で打ち切る
+        let mut github_client = client_for(address, 2);
+        let notifications = github_client
+            .get_notifications(None, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(notifications.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_get_notifications_not_modified() {
+        let address = spawn_mock_server(|_, _| {
+            ("304 Not Modified".to_string(), String::new(), String::new())
+        })
+        .await;
+
+        let mut github_client = client_for(address, 10);
+        let result = github_client
+            .get_notifications(None, Some("\"page1\""))
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let github_config = crate::config::GitHubConfig {
+            retry_base_sec: 2,
+            retry_max_sec: 10,
+            ..Default::default()
+        };
+
+        // 揺らぎの最大値では 2, 4, 8 秒と倍になり、上限で頭打ちになる
+        let delays: Vec<u64> = (0..5)
+            .map(|attempt| retry_delay(attempt, &github_config, None, 1.0).as_secs())
+            .collect();
+        assert_eq!(delays, vec![2, 4, 8, 10, 10]);
+        // 揺らぎの最小値では半分
+        assert_eq!(
+            retry_delay(1, &github_config, None, 0.0),
+            Duration::from_secs(2)
+        );
+        // Retry-After がある場合はそれに従う
+        assert_eq!(
+            retry_delay(0, &github_config, Some(Duration::from_secs(30)), 0.5),
+            Duration::from_secs(30)
+        );
+    }
+
+    #[test]
+    fn test_retry_after_from_headers() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after_from_headers(&headers), None);
+        headers.insert("Retry-After", HeaderValue::from_static("60"));
+        assert_eq!(
+            retry_after_from_headers(&headers),
+            Some(Duration::from_secs(60))
+        );
+        // HTTP 日付形式は未対応（指数バックオフにフォールバック）
+        headers.insert(
+            "Retry-After",
+            HeaderValue::from_static("Wed, 21 Oct 2026 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after_from_headers(&headers), None);
+    }
+
+    #[test]
+    fn test_rate_limit_kind() {
+        use reqwest::StatusCode;
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let headers = HeaderMap::new();
+        let secondary =
+            r#"{"message":"You have exceeded a secondary rate limit. Please wait a few minutes."}"#;
+        let primary = r#"{"message":"API rate limit exceeded for user ID 1."}"#;
+
+        assert_eq!(
+            rate_limit_kind(StatusCode::FORBIDDEN, &headers, secondary),
+            Some(RateLimitKind::Secondary)
+        );
+        assert_eq!(
+            rate_limit_kind(StatusCode::TOO_MANY_REQUESTS, &headers, secondary),
+            Some(RateLimitKind::Secondary)
+        );
+        assert_eq!(
+            rate_limit_kind(StatusCode::FORBIDDEN, &headers, primary),
+            Some(RateLimitKind::Primary)
+        );
+        // レート制限以外の 403 や、403 / 429 以外のステータス
+        assert_eq!(
+            rate_limit_kind(StatusCode::FORBIDDEN, &headers, "Resource not accessible"),
+            None
+        );
+        assert_eq!(
+            rate_limit_kind(StatusCode::INTERNAL_SERVER_ERROR, &headers, secondary),
+            None
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("0"));
+        assert_eq!(
+            rate_limit_kind(StatusCode::FORBIDDEN, &headers, ""),
+            Some(RateLimitKind::Primary)
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert("Retry-After", HeaderValue::from_static("30"));
+        assert_eq!(
+            rate_limit_kind(StatusCode::FORBIDDEN, &headers, ""),
+            Some(RateLimitKind::Secondary)
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_error() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        assert!(matches!(
+            rate_limit_error(RateLimitKind::Secondary, &headers, "slow down"),
+            AuthError::SecondaryRateLimit { retry_after, .. }
+                if retry_after == SECONDARY_RATE_LIMIT_DEFAULT_WAIT
+        ));
+        headers.insert("Retry-After", HeaderValue::from_static("90"));
+        assert!(matches!(
+            rate_limit_error(RateLimitKind::Secondary, &headers, "slow down"),
+            AuthError::SecondaryRateLimit { retry_after, .. }
+                if retry_after == Duration::from_secs(90)
+        ));
+        assert!(matches!(
+            rate_limit_error(RateLimitKind::Primary, &headers, "exhausted"),
+            AuthError::RateLimitExceeded(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_notifications_retries_transient_errors() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // 最初の2回は 503（2回目は Retry-After 付き）、3回目で成功する
+        static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+        let address = spawn_mock_server(|_, _| match REQUESTS.fetch_add(1, Ordering::SeqCst) {
+            0 => (
+                "503 Service Unavailable".to_string(),
+                String::new(),
+                String::new(),
+            ),
+            1 => (
+                "503 Service Unavailable".to_string(),
+                "Retry-After: 0\r\n".to_string(),
+                String::new(),
+            ),
+            _ => (
+                "200 OK".to_string(),
+                "Content-Type: application/json\r\n".to_string(),
+                format!("[{}]", notification_json("1")),
+            ),
+        })
+        .await;
+
+        let mut auth_manager = AuthManager::new().unwrap();
+        auth_manager.token_info = Some(crate::TokenInfo::from_pat("ghp_test"));
+        let github_config = crate::config::GitHubConfig {
+            api_base_url: Some(format!("http://{}", address)),
+            retry_count: 2,
+            retry_base_sec: 0,
+            ..Default::default()
+        };
+        let mut github_client = GitHubClient::with_config(auth_manager, &github_config).unwrap();
+        let notifications = github_client
+            .get_notifications(None, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(REQUESTS.load(Ordering::SeqCst), 3);
+
+        // デフォルト（再試行なし）ではそのままエラーになる
+        let address = spawn_mock_server(|_, _| {
+            (
+                "503 Service Unavailable".to_string(),
+                String::new(),
+                String::new(),
+            )
+        })
+        .await;
+        let mut github_client = client_for(address, 10);
+        assert!(github_client.get_notifications(None, None).await.is_err());
+    }
+
     // 以下はマockサーバー等でのテストになるため、基本的な構造テストのみ
     #[test]
     fn test_notification_struct() {
@@ -199,4 +1172,72 @@ mod tests {
         assert_eq!(notification.subject.title, "A new issue");
         assert_eq!(notification.repository.name, "repo");
     }
+
+    #[tokio::test]
+    async fn test_get_subjects_labels_respects_concurrency_limit() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // リクエストのパスをラベル名として返し、同時に処理中のリクエスト数の最大値を記録するサーバー
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let active = Arc::new(AtomicUsize::new(0));
+        let max_active = Arc::new(AtomicUsize::new(0));
+        let (server_active, server_max) = (active.clone(), max_active.clone());
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let (active, max_active) = (server_active.clone(), server_max.clone());
+                tokio::spawn(async move {
+                    let current = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_active.fetch_max(current, Ordering::SeqCst);
+
+                    let mut buffer = [0u8; 1024];
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    let request = String::from_utf8_lossy(&buffer[..read]);
+                    let path = request.split_whitespace().nth(1).unwrap().to_string();
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+                    let body = format!(r#"{{"labels":[{{"name":"{}"}}]}}"#, path);
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    socket.write_all(response.as_bytes()).await.unwrap();
+                });
+            }
+        });
+
+        let mut auth_manager = AuthManager::new().unwrap();
+        auth_manager.token_info = Some(crate::TokenInfo::from_pat("ghp_test"));
+        let mut github_client = GitHubClient::new(auth_manager).unwrap();
+        let urls: Vec<String> = (1..=6)
+            .map(|i| format!("http://{}/issues/{}", address, i))
+            .collect();
+
+        let results = github_client.get_subjects_labels(&urls, 2).await;
+
+        // すべてのラベルを要求した順で取得し、同時リクエスト数は上限を超えない
+        let labels: Vec<Vec<String>> = results.into_iter().map(Result::unwrap).collect();
+        let expected: Vec<Vec<String>> = (1..=6).map(|i| vec![format!("/issues/{}", i)]).collect();
+        assert_eq!(labels, expected);
+        assert!(max_active.load(Ordering::SeqCst) <= 2);
+        assert!(max_active.load(Ordering::SeqCst) >= 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_subjects_labels_without_token() {
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let urls = vec![
+            "https://example.com/1".to_string(),
+            "https://example.com/2".to_string(),
+        ];
+
+        let results = github_client.get_subjects_labels(&urls, 4).await;
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(Result::is_err));
+    }
 }
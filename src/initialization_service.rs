@@ -1,7 +1,10 @@
+use crate::config::{AuthConfig, MissingTokenBehavior};
 use crate::{
     AuthError, Config, ConfigProvider, DesktopNotifier, ExitHandler, GitHubClient, InitializedApp,
     MessageHandler, StateManager, auth_manager::AuthManager,
 };
+use std::io::IsTerminal;
+use std::time::Duration;
 
 /// Service that handles application initialization with dependency injection
 pub struct AppInitializationService<'a> {
@@ -29,7 +32,7 @@ impl<'a> AppInitializationService<'a> {
         tracing::info!("GitHub Notifier starting...");
 
         // Load config first to get PAT
-        let config = self.config_provider.load_config().unwrap_or_else(|e| {
+        let mut config = self.config_provider.load_config().unwrap_or_else(|e| {
             self.message_handler
                 .eprint(&format!("Failed to load config: {}", e));
             self.exit_handler.exit(1);
@@ -37,36 +40,20 @@ impl<'a> AppInitializationService<'a> {
             Config::default()
         });
 
-        let mut auth_manager = AuthManager::new()?;
+        let pat = match configured_pat(&config).or_else(stored_pat) {
+            Some(pat) => pat,
+            None => match self.resolve_missing_token(&mut config).await {
+                Some(pat) => pat,
+                None => {
+                    self.exit_handler.exit(1);
+                    return Err(AuthError::GeneralError("No PAT available".to_string()));
+                }
+            },
+        };
 
-        // Set the PAT from config if available
-        if let Some(pat) = &config.pat {
-            if !pat.trim().is_empty() {
-                use secrecy::SecretString;
-                let token_info = crate::TokenInfo {
-                    access_token: SecretString::new(pat.trim().to_string()),
-                    token_type: "Bearer".to_string(),
-                    expires_at: None,    // PATs don't expire by default
-                    refresh_token: None, // No refresh token for PAT
-                    refresh_token_expires_at: None,
-                };
-                auth_manager.token_info = Some(token_info);
-            } else {
-                tracing::error!("PAT is set in config but is empty");
-                self.message_handler
-                    .eprint("PAT is set in config but is empty.");
-                self.exit_handler.exit(1);
-            }
-        } else {
-            tracing::error!("No PAT found in config file");
-            self.message_handler.eprint(
-                "No PAT found in config file. Please add 'pat = \"your_token_here\"' to your config file."
-            );
-            self.message_handler.eprint(
-                "Create a PAT at: https://github.com/settings/tokens with 'notifications' scope",
-            );
-            self.exit_handler.exit(1);
-        }
+        let mut auth_manager = AuthManager::new()?;
+        auth_manager.set_github_config(config.github.clone());
+        auth_manager.token_info = Some(crate::TokenInfo::from_pat(&pat));
 
         // Validate the PAT token
         match auth_manager.validate_token().await {
@@ -103,9 +90,16 @@ impl<'a> AppInitializationService<'a> {
         }
 
         // Initialize clients and services
-        let github_client = GitHubClient::new(auth_manager).unwrap();
+        let mut github_client = GitHubClient::with_config(auth_manager, &config.github)?;
+        if config.debug.store_raw_payload {
+            github_client.set_raw_payload_store(Some(
+                crate::raw_payload::RawPayloadStore::with_default_dir(
+                    config.debug.raw_payload_max_entries,
+                ),
+            ));
+        }
         let state_manager = StateManager::new().unwrap();
-        let notifier = Box::new(DesktopNotifier);
+        let notifier = Box::new(DesktopNotifier::new(&config.notification));
 
         tracing::info!("GitHub Notifier running with authenticated access");
 
@@ -116,10 +110,138 @@ impl<'a> AppInitializationService<'a> {
             notifier,
         })
     }
+
+    /// Handle a missing PAT according to `auth.on_missing_token`
+    ///
+    /// Returns the token to use, or `None` if the application should exit
+    async fn resolve_missing_token(&self, config: &mut Config) -> Option<String> {
+        let interactive = std::io::stdin().is_terminal();
+        match missing_token_action(&config.auth, interactive) {
+            MissingTokenAction::Exit => {
+                tracing::error!("No PAT found in config file");
+                self.message_handler.eprint(
+                    "No PAT found in config file. Please add 'pat = \"your_token_here\"' to your config file."
+                );
+                self.message_handler.eprint(
+                    "Create a PAT at: https://github.com/settings/tokens with 'notifications' scope",
+                );
+                None
+            }
+            MissingTokenAction::DeviceFlowUnsupported => {
+                tracing::error!("auth.on_missing_token = \"device_flow\" is not yet supported");
+                self.message_handler.eprint(
+                    "No PAT found in config file, and auth.on_missing_token = \"device_flow\" is not yet supported (see backlog task-54).",
+                );
+                self.message_handler.eprint(
+                    "Run 'gh-notifier auth login' or set on_missing_token to \"error\", \"prompt\" or \"wait\".",
+                );
+                None
+            }
+            MissingTokenAction::Prompt => {
+                self.message_handler.print(
+                    "No PAT found in config file. Create one at https://github.com/settings/tokens with 'notifications' scope.",
+                );
+                match rpassword::prompt_password("GitHub Personal Access Token: ") {
+                    Ok(pat) if !pat.trim().is_empty() => {
+                        self.message_handler.print(
+                            "Using the entered PAT for this session. Add 'pat = \"...\"' to your config file to skip this prompt.",
+                        );
+                        Some(pat.trim().to_string())
+                    }
+                    Ok(_) => {
+                        self.message_handler.eprint("No PAT entered.");
+                        None
+                    }
+                    Err(e) => {
+                        self.message_handler
+                            .eprint(&format!("Failed to read the PAT: {}", e));
+                        None
+                    }
+                }
+            }
+            MissingTokenAction::Wait(retry_interval) => {
+                tracing::warn!(
+                    "No PAT found in config file or keychain, waiting for one to be added (checking every {}s)",
+                    retry_interval.as_secs()
+                );
+                loop {
+                    tokio::time::sleep(retry_interval).await;
+                    match self.config_provider.load_config() {
+                        Ok(reloaded) => {
+                            if let Some(pat) = configured_pat(&reloaded) {
+                                tracing::info!("PAT found in config file, continuing startup");
+                                *config = reloaded;
+                                return Some(pat);
+                            }
+                            if let Some(pat) = stored_pat() {
+                                tracing::info!("PAT found in keychain, continuing startup");
+                                *config = reloaded;
+                                return Some(pat);
+                            }
+                            tracing::debug!("Still no PAT in config file or keychain");
+                        }
+                        Err(e) => tracing::warn!("Failed to reload config: {}", e),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What to do at startup when no PAT is configured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MissingTokenAction {
+    /// Print how to configure a PAT and exit
+    Exit,
+    /// Ask for a PAT on the terminal
+    Prompt,
+    /// Re-check the config file at the given interval
+    Wait(Duration),
+    /// The device flow was requested but is not implemented yet; explain and exit
+    DeviceFlowUnsupported,
+}
+
+/// Decide how to handle a missing PAT based on the config and whether stdin is a terminal
+pub(crate) fn missing_token_action(auth: &AuthConfig, interactive: bool) -> MissingTokenAction {
+    match auth.on_missing_token {
+        MissingTokenBehavior::Error => MissingTokenAction::Exit,
+        MissingTokenBehavior::Prompt if interactive => MissingTokenAction::Prompt,
+        // Nobody can answer the prompt (e.g. started by a service manager)
+        MissingTokenBehavior::Prompt => MissingTokenAction::Exit,
+        MissingTokenBehavior::Wait => {
+            MissingTokenAction::Wait(Duration::from_secs(auth.missing_token_retry_sec.max(1)))
+        }
+        MissingTokenBehavior::DeviceFlow => MissingTokenAction::DeviceFlowUnsupported,
+    }
+}
+
+/// The PAT saved with `auth login --keychain` (keyring or token file), used when the config has none
+pub(crate) fn stored_pat() -> Option<String> {
+    use secrecy::ExposeSecret;
+
+    let mut auth_manager = AuthManager::new().ok()?;
+    let token_info = auth_manager.load_token_from_storage().ok()??;
+    let pat = token_info.access_token.expose_secret().trim().to_string();
+    (!pat.is_empty()).then_some(pat)
+}
+
+/// The PAT from the config, if present and not blank
+pub(crate) fn configured_pat(config: &Config) -> Option<String> {
+    match config.pat.as_deref().map(str::trim) {
+        Some("") => {
+            tracing::warn!("PAT is set in config but is empty");
+            None
+        }
+        Some(pat) => Some(pat.to_string()),
+        None => None,
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{MissingTokenAction, configured_pat, missing_token_action};
+    use crate::config::{AuthConfig, MissingTokenBehavior};
+    use std::time::Duration;
 
     use crate::traits::{ConfigProvider, ExitHandler, MessageHandler};
     use std::sync::{Arc, Mutex};
@@ -223,4 +345,53 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().to_string(), "Config loading error");
     }
+
+    #[test]
+    fn test_missing_token_action() {
+        let auth = |on_missing_token| AuthConfig {
+            on_missing_token,
+            missing_token_retry_sec: 30,
+        };
+
+        let error = auth(MissingTokenBehavior::Error);
+        assert_eq!(missing_token_action(&error, true), MissingTokenAction::Exit);
+        assert_eq!(
+            missing_token_action(&error, false),
+            MissingTokenAction::Exit
+        );
+
+        let prompt = auth(MissingTokenBehavior::Prompt);
+        assert_eq!(
+            missing_token_action(&prompt, true),
+            MissingTokenAction::Prompt
+        );
+        assert_eq!(
+            missing_token_action(&prompt, false),
+            MissingTokenAction::Exit
+        );
+
+        let wait = auth(MissingTokenBehavior::Wait);
+        assert_eq!(
+            missing_token_action(&wait, false),
+            MissingTokenAction::Wait(Duration::from_secs(30))
+        );
+
+        let device_flow = auth(MissingTokenBehavior::DeviceFlow);
+        for interactive in [true, false] {
+            assert_eq!(
+                missing_token_action(&device_flow, interactive),
+                MissingTokenAction::DeviceFlowUnsupported
+            );
+        }
+    }
+
+    #[test]
+    fn test_configured_pat() {
+        let mut config = crate::Config::default();
+        assert_eq!(configured_pat(&config), None);
+        config.pat = Some("   ".to_string());
+        assert_eq!(configured_pat(&config), None);
+        config.pat = Some(" ghp_token ".to_string());
+        assert_eq!(configured_pat(&config).as_deref(), Some("ghp_token"));
+    }
 }
@@ -1,23 +1,39 @@
 pub mod app;
 pub mod auth;
 pub mod auth_manager;
+pub mod cli;
+pub mod concurrency;
 pub mod config;
+pub mod debug_bundle;
+pub mod dnd;
+pub mod doctor;
 pub mod errors;
+pub mod filter_suggestion;
 pub mod github_client;
 pub mod initialization_service;
 pub mod initializer;
 pub mod logger;
 pub mod models;
+pub mod paths;
 pub mod poller;
 pub mod polling;
+pub mod raw_payload;
 pub mod runtime;
 pub mod shutdown;
+pub mod slack;
 pub mod state;
+pub mod stats;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub mod token_storage;
 pub mod traits;
+pub mod webhook;
 
 pub use app::Application;
 pub use auth::AuthManager;
+pub use cli::{
+    AuthCommands, Cli, Commands, ConfigCommands, DebugCommands, FiltersCommands, OutputFormat,
+};
 pub use config::Config;
 pub use errors::AuthError;
 pub use github_client::GitHubClient;
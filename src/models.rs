@@ -18,6 +18,19 @@ pub struct TokenInfo {
     pub refresh_token_expires_at: Option<u64>,
 }
 
+impl TokenInfo {
+    /// Creates token info for a classic Personal Access Token
+    pub fn from_pat(pat: &str) -> Self {
+        TokenInfo {
+            access_token: SecretString::new(pat.trim().to_string()),
+            token_type: "Bearer".to_string(),
+            expires_at: None,    // PATs don't expire by default
+            refresh_token: None, // No refresh token for PAT
+            refresh_token_expires_at: None,
+        }
+    }
+}
+
 // Custom serialization for SecretString
 pub fn serialize_secret<S>(secret: &SecretString, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -86,6 +99,8 @@ pub struct NotificationRepository {
     pub name: String,
     pub full_name: String,
     pub private: bool,
+    #[serde(default)]
+    pub fork: bool,
 }
 
 #[cfg(test)]
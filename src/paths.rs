@@ -0,0 +1,161 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// プロセス全体で使用するプロファイル名（`--profile` で指定）
+static PROFILE: OnceLock<Option<String>> = OnceLock::new();
+
+/// プロセス全体で使用する設定ファイルのパス（`--config` で指定）
+static CONFIG_PATH: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// 使用するプロファイルを設定する（起動時に一度だけ呼び出す）
+pub fn set_profile(
+    profile: Option<String>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if let Some(name) = &profile {
+        validate_profile_name(name)?;
+    }
+
+    PROFILE
+        .set(profile)
+        .map_err(|_| "Profile has already been set".into())
+}
+
+/// 現在のプロファイル名を取得（未指定の場合はデフォルトプロファイル）
+pub fn current_profile() -> Option<&'static str> {
+    PROFILE.get().and_then(|p| p.as_deref())
+}
+
+/// 使用する設定ファイルのパスを設定する（起動時に一度だけ呼び出す）
+pub fn set_config_path(
+    path: Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    CONFIG_PATH
+        .set(path)
+        .map_err(|_| "Config path has already been set".into())
+}
+
+/// `--config` で指定された設定ファイルのパス（未指定の場合は `None`）
+pub fn config_path_override() -> Option<&'static Path> {
+    CONFIG_PATH.get().and_then(|p| p.as_deref())
+}
+
+/// プロファイル名の妥当性を検証する（ディレクトリ名として安全な名前のみ許可）
+pub fn validate_profile_name(name: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if name.is_empty() {
+        return Err("Profile name must not be empty".into());
+    }
+
+    if name == "." || name == ".." {
+        return Err(format!("Invalid profile name: {}", name).into());
+    }
+
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    {
+        return Err(format!(
+            "Invalid profile name: {} (only letters, digits, '-', '_' and '.' are allowed)",
+            name
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// アプリケーションのベースディレクトリ（プロファイルを考慮しない）
+fn base_dir() -> PathBuf {
+    let mut path = dirs::config_dir()
+        .unwrap_or_else(|| std::env::current_dir().expect("現在のディレクトリが取得できません"));
+    path.push("gh-notifier");
+    path
+}
+
+/// 指定したプロファイルのデータディレクトリ
+///
+/// デフォルトプロファイル（`None`）は従来通り `<config_dir>/gh-notifier/` を使用し、
+/// 名前付きプロファイルは `<config_dir>/gh-notifier/profiles/<name>/` を使用する
+pub fn app_dir_for(profile: Option<&str>) -> PathBuf {
+    let mut path = base_dir();
+    if let Some(name) = profile {
+        path.push("profiles");
+        path.push(name);
+    }
+    path
+}
+
+/// 現在のプロファイルのデータディレクトリ
+pub fn app_dir() -> PathBuf {
+    app_dir_for(current_profile())
+}
+
+/// 指定したプロファイルの設定ファイルのパス
+pub fn config_file_path_for(profile: Option<&str>) -> PathBuf {
+    app_dir_for(profile).join("config.toml")
+}
+
+/// 指定したプロファイルの状態ファイルのパス
+pub fn state_file_path_for(profile: Option<&str>) -> PathBuf {
+    app_dir_for(profile).join("state.json")
+}
+
+/// 指定したプロファイルのトークンファイルのパス
+pub fn token_file_path_for(profile: Option<&str>) -> PathBuf {
+    app_dir_for(profile).join("token.json")
+}
+
+/// 設定ファイルのパス（`--config` が指定されている場合はそのパス）
+pub fn config_file_path() -> PathBuf {
+    match config_path_override() {
+        Some(path) => path.to_path_buf(),
+        None => config_file_path_for(current_profile()),
+    }
+}
+
+/// 状態ファイルのパス
+pub fn state_file_path() -> PathBuf {
+    state_file_path_for(current_profile())
+}
+
+/// トークンファイルのパス
+pub fn token_file_path() -> PathBuf {
+    token_file_path_for(current_profile())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_profile_preserves_paths() {
+        let dir = app_dir_for(None);
+        assert!(dir.ends_with("gh-notifier"));
+        assert_eq!(state_file_path_for(None), dir.join("state.json"));
+        assert_eq!(config_file_path_for(None), dir.join("config.toml"));
+    }
+
+    #[test]
+    fn test_profiles_use_distinct_files() {
+        let work = state_file_path_for(Some("work"));
+        let personal = state_file_path_for(Some("personal"));
+
+        assert_ne!(work, personal);
+        assert_ne!(work, state_file_path_for(None));
+        assert!(work.ends_with("profiles/work/state.json"));
+        assert!(personal.ends_with("profiles/personal/state.json"));
+        assert_ne!(
+            token_file_path_for(Some("work")),
+            token_file_path_for(Some("personal"))
+        );
+    }
+
+    #[test]
+    fn test_validate_profile_name() {
+        assert!(validate_profile_name("work").is_ok());
+        assert!(validate_profile_name("my_profile-2").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("..").is_err());
+        assert!(validate_profile_name("../etc").is_err());
+        assert!(validate_profile_name("a/b").is_err());
+    }
+}
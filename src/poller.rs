@@ -1,6 +1,17 @@
 use crate::{Config, GitHubClient, StateManager};
 use notify_rust::Notification;
 
+/// デスクトップ通知の表示時間
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationTimeout {
+    /// 通知サーバーのデフォルト
+    Default,
+    /// 閉じるまで表示
+    Never,
+    /// 指定したミリ秒後に自動的に閉じる
+    Milliseconds(u32),
+}
+
 pub trait Notifier: Send + Sync {
     fn send_notification(
         &self,
@@ -8,6 +19,29 @@ pub trait Notifier: Send + Sync {
         body: &str,
         url: &str,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 表示時間を指定して通知を送信（表示時間に対応していない Notifier では無視される）
+    fn send_notification_with_timeout(
+        &self,
+        title: &str,
+        body: &str,
+        url: &str,
+        _timeout: NotificationTimeout,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.send_notification(title, body, url)
+    }
+
+    /// 表示時間とサウンドの有無を指定して通知を送信（サウンドに対応していない Notifier では無視される）
+    fn send_notification_with_sound(
+        &self,
+        title: &str,
+        body: &str,
+        url: &str,
+        timeout: NotificationTimeout,
+        _sound: bool,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.send_notification_with_timeout(title, body, url, timeout)
+    }
 }
 
 pub struct Poller {
@@ -59,7 +93,70 @@ impl Poller {
     }
 }
 
-pub struct DesktopNotifier;
+#[derive(Debug, Clone, Default)]
+pub struct DesktopNotifier {
+    /// サウンドとして再生するファイル（`notification.sound_file`。未設定の場合はサウンドテーマの音）
+    sound_file: Option<String>,
+    /// 通知サーバーがサウンドを再生できるかどうか（最初にサウンドを鳴らす際に確認する）
+    server_plays_sound: std::sync::OnceLock<bool>,
+}
+
+/// サウンドの鳴らし方
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SoundOutput {
+    /// 鳴らさない
+    Silent,
+    /// 通知サーバーにサウンドのヒントを渡す
+    Hint,
+    /// 通知サーバーがサウンドに対応していないため、ターミナルのベルで代用する
+    TerminalBell,
+}
+
+fn sound_output(sound: bool, server_plays_sound: bool) -> SoundOutput {
+    match (sound, server_plays_sound) {
+        (false, _) => SoundOutput::Silent,
+        (true, true) => SoundOutput::Hint,
+        (true, false) => SoundOutput::TerminalBell,
+    }
+}
+
+/// ターミナルから起動している場合にベルを鳴らす（デーモンとして起動している場合は何もしない）
+fn ring_terminal_bell() {
+    use std::io::{IsTerminal, Write};
+
+    let mut stderr = std::io::stderr();
+    if stderr.is_terminal() {
+        let _ = stderr.write_all(b"\x07");
+        let _ = stderr.flush();
+    }
+}
+
+impl DesktopNotifier {
+    /// 通知の設定から作成
+    pub fn new(config: &crate::config::NotificationConfig) -> Self {
+        DesktopNotifier {
+            sound_file: config.sound_file.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// 通知サーバーの機能に `sound` が含まれるかどうか（確認できない場合は false）
+    fn server_plays_sound(&self) -> bool {
+        *self.server_plays_sound.get_or_init(|| {
+            notify_rust::get_capabilities()
+                .map(|capabilities| capabilities.iter().any(|c| c == "sound"))
+                .unwrap_or(false)
+        })
+    }
+
+    /// サウンドを鳴らす場合のヒント
+    fn sound_hint(&self) -> notify_rust::Hint {
+        match &self.sound_file {
+            Some(path) => notify_rust::Hint::SoundFile(path.clone()),
+            None => notify_rust::Hint::SoundName("message-new-instant".to_string()),
+        }
+    }
+}
 
 impl Notifier for DesktopNotifier {
     fn send_notification(
@@ -68,17 +165,60 @@ impl Notifier for DesktopNotifier {
         body: &str,
         url: &str, // url を使用する
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        Notification::new()
+        self.send_notification_with_timeout(title, body, url, NotificationTimeout::Default)
+    }
+
+    fn send_notification_with_timeout(
+        &self,
+        title: &str,
+        body: &str,
+        url: &str,
+        timeout: NotificationTimeout,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.send_notification_with_sound(title, body, url, timeout, false)
+    }
+
+    fn send_notification_with_sound(
+        &self,
+        title: &str,
+        body: &str,
+        url: &str,
+        timeout: NotificationTimeout,
+        sound: bool,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let (timeout, transient) = match timeout {
+            NotificationTimeout::Default => (notify_rust::Timeout::Default, true),
+            // 閉じるまで表示する通知は通知履歴にも残す
+            NotificationTimeout::Never => (notify_rust::Timeout::Never, false),
+            NotificationTimeout::Milliseconds(ms) => (notify_rust::Timeout::Milliseconds(ms), true),
+        };
+
+        let mut notification = Notification::new();
+        notification
             .summary(title)
             .body(body)
             .icon("dialog-information") // 任意のアイコン
-            .hint(notify_rust::Hint::Transient(true)) // 通知を自動的に消す
+            .timeout(timeout)
+            .hint(notify_rust::Hint::Transient(transient)) // 通知を自動的に消す
             .hint(notify_rust::Hint::Custom(
                 "default-action".to_string(),
                 url.to_string(),
-            ))
+            ));
+        let output = sound_output(sound, sound && self.server_plays_sound());
+        match output {
+            SoundOutput::Hint => {
+                notification.hint(self.sound_hint());
+            }
+            SoundOutput::Silent | SoundOutput::TerminalBell => {
+                notification.hint(notify_rust::Hint::SuppressSound(true));
+            }
+        }
+        notification
             .show()
             .map_err(|e| Box::new(std::io::Error::other(e)))?;
+        if output == SoundOutput::TerminalBell {
+            ring_terminal_bell();
+        }
         Ok(())
     }
 }
@@ -125,6 +265,21 @@ impl Notifier for WindowsNotifier {
     }
 }
 
+/// 通知を表示せずにログに出力する Notifier（`--dry-run`）
+pub struct DryRunNotifier;
+
+impl Notifier for DryRunNotifier {
+    fn send_notification(
+        &self,
+        title: &str,
+        body: &str,
+        url: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        tracing::info!(title = %title, url = %url, "Dry run: would notify\n{}", body);
+        Ok(())
+    }
+}
+
 #[allow(dead_code)]
 struct DummyNotifier;
 
@@ -175,9 +330,11 @@ mod tests {
     #[test]
     fn test_filter_new_notifications() {
         use crate::config::NotificationFilter;
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         let auth_manager = AuthManager::new().unwrap();
         let github_client = GitHubClient::new(auth_manager).unwrap();
         let state_manager = StateManager::new().unwrap();
@@ -206,6 +363,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "user/repo1".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -228,6 +386,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "user/repo2".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -250,9 +409,35 @@ mod tests {
         assert_eq!(new_notifications[0].id, "2");
     }
 
+    #[test]
+    fn test_desktop_notifier_sound_hint() {
+        assert_eq!(
+            DesktopNotifier::default().sound_hint(),
+            notify_rust::Hint::SoundName("message-new-instant".to_string())
+        );
+
+        let config = crate::config::NotificationConfig {
+            sound_file: Some("/usr/share/sounds/alert.oga".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            DesktopNotifier::new(&config).sound_hint(),
+            notify_rust::Hint::SoundFile("/usr/share/sounds/alert.oga".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sound_output() {
+        assert_eq!(sound_output(false, true), SoundOutput::Silent);
+        assert_eq!(sound_output(false, false), SoundOutput::Silent);
+        assert_eq!(sound_output(true, true), SoundOutput::Hint);
+        // 通知サーバーがサウンドに対応していない場合はターミナルのベル
+        assert_eq!(sound_output(true, false), SoundOutput::TerminalBell);
+    }
+
     #[test]
     fn test_desktop_notifier_send_notification() {
-        let notifier = DesktopNotifier;
+        let notifier = DesktopNotifier::default();
         // テストでは通知を表示しないが、エラーが発生しないことを確認
         let result = notifier.send_notification("Test Title", "Test Body", "https://example.com");
         assert!(result.is_ok());
@@ -0,0 +1,190 @@
+use crate::config::{NotificationConfig, PollingConfig};
+use chrono::{Datelike, NaiveDateTime, NaiveTime, Weekday};
+
+/// 時間帯と曜日の指定（`polling.active_hours` / `polling.active_days`、`notification.quiet_hours` / `notification.quiet_days`）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveSchedule {
+    /// 開始と終了の時刻（終了が開始より前の場合は日付をまたぐ時間帯）
+    hours: Option<(NaiveTime, NaiveTime)>,
+    /// ポーリングを行う曜日（空の場合は毎日）
+    days: Vec<Weekday>,
+}
+
+impl ActiveSchedule {
+    /// 設定から作成（どちらも指定されていない場合は常にポーリングするため `None`）
+    pub fn from_config(polling: &PollingConfig) -> Result<Option<Self>, String> {
+        Self::parse(
+            polling.active_hours.as_deref(),
+            &polling.active_days,
+            ("polling.active_hours", "polling.active_days"),
+        )
+    }
+
+    /// 通知を抑制する時間帯の設定から作成（どちらも指定されていない場合は抑制しないため `None`）
+    pub fn from_quiet_hours(notification: &NotificationConfig) -> Result<Option<Self>, String> {
+        Self::parse(
+            notification.quiet_hours.as_deref(),
+            &notification.quiet_days,
+            ("notification.quiet_hours", "notification.quiet_days"),
+        )
+    }
+
+    /// 時間帯と曜日を解析（`keys` はエラーメッセージに表示する設定項目の名前）
+    fn parse(
+        hours: Option<&str>,
+        days: &[String],
+        keys: (&str, &str),
+    ) -> Result<Option<Self>, String> {
+        if hours.is_none() && days.is_empty() {
+            return Ok(None);
+        }
+
+        let hours = hours.map(|value| parse_hours(value, keys.0)).transpose()?;
+        let days = days
+            .iter()
+            .map(|day| parse_weekday(day, keys.1))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(ActiveSchedule { hours, days }))
+    }
+
+    /// `now`（ローカル時刻）が指定した時間帯かどうか
+    ///
+    /// 日付をまたぐ時間帯の深夜の部分は、開始した日の曜日として扱う
+    pub fn is_active(&self, now: NaiveDateTime) -> bool {
+        let time = now.time();
+        let (in_hours, day) = match self.hours {
+            None => (true, now.weekday()),
+            Some((start, end)) if start <= end => (start <= time && time < end, now.weekday()),
+            Some((start, end)) => {
+                if time >= start {
+                    (true, now.weekday())
+                } else {
+                    (time < end, now.weekday().pred())
+                }
+            }
+        };
+        in_hours && (self.days.is_empty() || self.days.contains(&day))
+    }
+}
+
+/// "09:00-18:00" 形式の時間帯を解析
+fn parse_hours(value: &str, key: &str) -> Result<(NaiveTime, NaiveTime), String> {
+    let invalid = || format!("Invalid {} '{}': expected HH:MM-HH:MM", key, value);
+    let (start, end) = value.split_once('-').ok_or_else(invalid)?;
+    let parse = |time: &str| NaiveTime::parse_from_str(time.trim(), "%H:%M").map_err(|_| invalid());
+    let (start, end) = (parse(start)?, parse(end)?);
+    if start == end {
+        return Err(format!(
+            "Invalid {} '{}': start and end must differ",
+            key, value
+        ));
+    }
+    Ok((start, end))
+}
+
+/// 曜日の名前（"mon", "Tuesday" など、大文字小文字は区別しない）を解析
+fn parse_weekday(value: &str, key: &str) -> Result<Weekday, String> {
+    value
+        .trim()
+        .parse::<Weekday>()
+        .map_err(|_| format!("Invalid {} entry '{}'", key, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(active_hours: Option<&str>, active_days: &[&str]) -> ActiveSchedule {
+        let polling = PollingConfig {
+            active_hours: active_hours.map(str::to_string),
+            active_days: active_days.iter().map(|d| d.to_string()).collect(),
+            ..Default::default()
+        };
+        ActiveSchedule::from_config(&polling).unwrap().unwrap()
+    }
+
+    fn at(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_work_hours_pause_and_resume() {
+        let work = schedule(Some("09:00-18:00"), &["mon", "tue", "wed", "thu", "fri"]);
+
+        // 2026-10-14 は水曜日
+        assert!(!work.is_active(at("2026-10-14 08:59")));
+        assert!(work.is_active(at("2026-10-14 09:00")));
+        assert!(work.is_active(at("2026-10-14 17:59")));
+        assert!(!work.is_active(at("2026-10-14 18:00")));
+        // 土曜日は時間帯に関わらず停止
+        assert!(!work.is_active(at("2026-10-17 10:00")));
+    }
+
+    #[test]
+    fn test_overnight_hours() {
+        let night = schedule(Some("22:00-06:00"), &["fri"]);
+
+        assert!(night.is_active(at("2026-10-16 23:00")));
+        // 土曜日の深夜は金曜日の時間帯の続き
+        assert!(night.is_active(at("2026-10-17 05:59")));
+        assert!(!night.is_active(at("2026-10-17 06:00")));
+        assert!(!night.is_active(at("2026-10-17 23:00")));
+    }
+
+    #[test]
+    fn test_from_config() {
+        assert_eq!(
+            ActiveSchedule::from_config(&PollingConfig::default()),
+            Ok(None)
+        );
+        // 曜日のみの指定は終日
+        let days_only = schedule(None, &["Saturday"]);
+        assert!(days_only.is_active(at("2026-10-17 03:00")));
+        assert!(!days_only.is_active(at("2026-10-18 03:00")));
+
+        for (hours, days) in [
+            (Some("9-18"), vec![]),
+            (Some("09:00-09:00"), vec![]),
+            (None, vec!["someday".to_string()]),
+        ] {
+            let polling = PollingConfig {
+                active_hours: hours.map(str::to_string),
+                active_days: days,
+                ..Default::default()
+            };
+            assert!(ActiveSchedule::from_config(&polling).is_err());
+        }
+    }
+
+    #[test]
+    fn test_quiet_hours() {
+        let quiet = |hours: Option<&str>, days: &[&str]| {
+            let notification = NotificationConfig {
+                quiet_hours: hours.map(str::to_string),
+                quiet_days: days.iter().map(|d| d.to_string()).collect(),
+                ..Default::default()
+            };
+            ActiveSchedule::from_quiet_hours(&notification)
+        };
+
+        assert_eq!(quiet(None, &[]), Ok(None));
+
+        // 日付をまたぐ時間帯（2026-10-14 は水曜日）
+        let night = quiet(Some("22:00-07:00"), &[]).unwrap().unwrap();
+        assert!(night.is_active(at("2026-10-14 23:30")));
+        assert!(night.is_active(at("2026-10-15 02:00")));
+        assert!(!night.is_active(at("2026-10-15 07:00")));
+        assert!(!night.is_active(at("2026-10-14 12:00")));
+
+        // 曜日を指定した場合はその曜日に始まる時間帯のみ
+        let weeknights = quiet(Some("22:00-07:00"), &["mon", "tue", "wed", "thu", "fri"])
+            .unwrap()
+            .unwrap();
+        assert!(weeknights.is_active(at("2026-10-17 02:00"))); // 金曜日の夜の続き
+        assert!(!weeknights.is_active(at("2026-10-17 23:00"))); // 土曜日の夜
+        assert!(!weeknights.is_active(at("2026-10-18 02:00")));
+
+        let error = quiet(Some("22-07"), &[]).unwrap_err();
+        assert!(error.contains("notification.quiet_hours"));
+    }
+}
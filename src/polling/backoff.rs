@@ -0,0 +1,76 @@
+use crate::github_client::RateLimit;
+use std::time::Duration;
+
+/// レート制限の残りが `threshold` を下回った場合に、次のポーリングまでの間隔を計算
+///
+/// リセット時刻までの時間を残りのリクエスト数で均等に分けた間隔にする（残りが 0 の場合はリセット時刻まで待つ）。
+/// 通常の間隔より長くならない場合は `None`
+pub fn backoff_interval(
+    rate_limit: &RateLimit,
+    base_interval: Duration,
+    threshold: u64,
+    now_unix: u64,
+) -> Option<Duration> {
+    if rate_limit.remaining >= threshold {
+        return None;
+    }
+
+    let until_reset = Duration::from_secs(rate_limit.reset.saturating_sub(now_unix));
+    let stretched = match rate_limit.remaining {
+        0 => until_reset,
+        remaining => until_reset / u32::try_from(remaining).unwrap_or(u32::MAX),
+    };
+    (stretched > base_interval).then_some(stretched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn rate_limit(remaining: u64, reset_in_sec: u64) -> RateLimit {
+        RateLimit {
+            limit: 5000,
+            remaining,
+            reset: NOW + reset_in_sec,
+        }
+    }
+
+    #[test]
+    fn test_backoff_interval() {
+        let base = Duration::from_secs(30);
+
+        // 残りが閾値以上の場合は通常の間隔
+        assert_eq!(
+            backoff_interval(&rate_limit(100, 3600), base, 100, NOW),
+            None
+        );
+        // リセットまでの時間を残りのリクエスト数で分ける
+        assert_eq!(
+            backoff_interval(&rate_limit(10, 3600), base, 100, NOW),
+            Some(Duration::from_secs(360))
+        );
+        // 残りが 0 の場合はリセット時刻まで待つ
+        assert_eq!(
+            backoff_interval(&rate_limit(0, 600), base, 100, NOW),
+            Some(Duration::from_secs(600))
+        );
+        // 通常の間隔で足りる場合は延ばさない
+        assert_eq!(backoff_interval(&rate_limit(50, 600), base, 100, NOW), None);
+        // リセット時刻を過ぎている場合も延ばさない
+        assert_eq!(
+            backoff_interval(
+                &RateLimit {
+                    limit: 5000,
+                    remaining: 0,
+                    reset: NOW - 10,
+                },
+                base,
+                100,
+                NOW
+            ),
+            None
+        );
+    }
+}
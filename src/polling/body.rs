@@ -0,0 +1,193 @@
+use crate::config::NotificationConfig;
+
+/// 省略したことを示す記号
+const ELLIPSIS: char = '…';
+
+/// デスクトップ通知に表示する本文を整形（Markdown の記号を取り除き、行数・文字数の上限で省略）
+pub fn format_popup_body(body: &str, config: &NotificationConfig) -> String {
+    let body = strip_markdown(body);
+    let body = match config.body_max_lines {
+        Some(max_lines) => truncate_lines(&body, max_lines),
+        None => body,
+    };
+    match config.body_max_chars {
+        Some(max_chars) => truncate_chars(&body, max_chars),
+        None => body,
+    }
+}
+
+/// Markdown の見出し・強調・インラインコード・リンクの記号を取り除く
+///
+/// URL（`https://...`）の中の `_` や `*` はそのまま残す
+pub fn strip_markdown(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let line = line.trim_start();
+            let line = match line.trim_start_matches('#') {
+                rest if rest.len() < line.len() && (rest.is_empty() || rest.starts_with(' ')) => {
+                    rest.trim_start()
+                }
+                _ => line,
+            };
+            strip_inline_markdown(line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn strip_inline_markdown(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("http://") || rest.starts_with("https://") {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            result.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+
+        // [text](url) → text
+        if c == '['
+            && let Some(close) = rest.find("](")
+            && let Some(paren) = rest[close..].find(')')
+        {
+            result.push_str(&rest[1..close]);
+            rest = &rest[close + paren + 1..];
+            continue;
+        }
+
+        if matches!(c, '*' | '`' | '~') || (c == '_' && is_emphasis_underscore(&result, rest)) {
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        result.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    result
+}
+
+/// 単語の中の `_`（`snake_case` など）は強調の記号として扱わない
+fn is_emphasis_underscore(before: &str, rest: &str) -> bool {
+    let prev_is_word = before.chars().last().is_some_and(char::is_alphanumeric);
+    let next_is_word = rest[1..].chars().next().is_some_and(char::is_alphanumeric);
+    !(prev_is_word && next_is_word)
+}
+
+/// 最初の `max_lines` 行のみを残し、省略した場合は最後の行に省略記号を付ける
+pub fn truncate_lines(text: &str, max_lines: usize) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.len() <= max_lines {
+        return text.to_string();
+    }
+    if max_lines == 0 {
+        return ELLIPSIS.to_string();
+    }
+
+    let mut truncated = lines[..max_lines].join("\n");
+    truncated.push(ELLIPSIS);
+    truncated
+}
+
+/// `max_chars` 文字（省略記号を含む）以内に省略する
+///
+/// できるだけ単語の途中で切らないように直前の空白で切り、適当な空白がない場合は文字単位で切る
+pub fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+
+    let keep = max_chars - 1;
+    let byte_end = text
+        .char_indices()
+        .nth(keep)
+        .map(|(index, _)| index)
+        .unwrap_or(text.len());
+    let head = &text[..byte_end];
+
+    // 単語の区切りで終わっていなければ、残す部分の半分より後ろにある空白で切る
+    let ends_at_word_boundary = text[byte_end..].starts_with(char::is_whitespace);
+    let cut = if ends_at_word_boundary {
+        head.len()
+    } else {
+        head.rfind(char::is_whitespace)
+            .filter(|&index| head[..index].chars().count() * 2 >= keep)
+            .unwrap_or(head.len())
+    };
+
+    let mut truncated = head[..cut].trim_end().to_string();
+    truncated.push(ELLIPSIS);
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_chars_boundaries() {
+        // 上限以内はそのまま
+        assert_eq!(truncate_chars("short", 5), "short");
+        // 単語の境界で切る
+        assert_eq!(
+            truncate_chars("hello wonderful world", 18),
+            "hello wonderful…"
+        );
+        // 空白が前半にしかない場合は単語の途中で切る
+        assert_eq!(truncate_chars("hello wonderful world", 12), "hello wonde…");
+        assert_eq!(truncate_chars("fix the parser bug", 15), "fix the parser…");
+        // 空白がない場合は文字単位で切る
+        assert_eq!(truncate_chars("abcdefghij", 5), "abcd…");
+        // マルチバイト文字の途中で切らない
+        assert_eq!(truncate_chars("通知のタイトルが長い", 5), "通知のタ…");
+        assert_eq!(truncate_chars("anything", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_lines() {
+        assert_eq!(truncate_lines("a\nb", 2), "a\nb");
+        assert_eq!(truncate_lines("a\nb\nc", 2), "a\nb…");
+        assert_eq!(truncate_lines("a\nb", 0), "…");
+    }
+
+    #[test]
+    fn test_strip_markdown() {
+        assert_eq!(strip_markdown("## Release notes"), "Release notes");
+        assert_eq!(
+            strip_markdown("Fix **bold** and `code` in _docs_"),
+            "Fix bold and code in docs"
+        );
+        assert_eq!(
+            strip_markdown("See [the docs](https://example.com/a_b)"),
+            "See the docs"
+        );
+        // 単語の中の `_`、URL、見出しではない `#` はそのまま
+        assert_eq!(
+            strip_markdown("Rename snake_case in #123\nURL: https://github.com/o/r_x/pull/1"),
+            "Rename snake_case in #123\nURL: https://github.com/o/r_x/pull/1"
+        );
+    }
+
+    #[test]
+    fn test_format_popup_body() {
+        let config = NotificationConfig {
+            body_max_lines: Some(1),
+            body_max_chars: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(
+            format_popup_body("**Bump** deps\nsecond", &config),
+            "Bump deps…"
+        );
+
+        // 上限を設定しない場合は Markdown の記号のみを取り除く
+        let unlimited = NotificationConfig::default();
+        assert_eq!(
+            format_popup_body("# Title\nline 2", &unlimited),
+            "Title\nline 2"
+        );
+    }
+}
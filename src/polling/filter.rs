@@ -1,4 +1,5 @@
 use crate::{Config, Notification, StateManager};
+use std::collections::HashMap;
 
 /// 指定された最終確認日時以降の通知のみを抽出
 pub fn filter_new_notifications<'a>(
@@ -6,39 +7,163 @@ pub fn filter_new_notifications<'a>(
     state_manager: &StateManager,
     config: &Config,
 ) -> Vec<&'a Notification> {
-    let filtered_notifications: Vec<&'a Notification> =
-        if let Some(last_checked) = state_manager.get_last_checked_at() {
-            notifications
-                .iter()
-                .filter(|n| n.updated_at.as_str() > last_checked)
-                .collect()
-        } else {
-            // 最終確認日時がない場合はすべて新しいと見なす
-            notifications.iter().collect()
-        };
+    filter_new_notifications_with_stats(
+        notifications,
+        state_manager,
+        config,
+        &mut FilterStats::default(),
+    )
+}
+
+/// `filter_new_notifications` と同様に抽出し、フィルタで除外された通知の件数を `stats` に記録
+pub fn filter_new_notifications_with_stats<'a>(
+    notifications: &'a [Notification],
+    state_manager: &StateManager,
+    config: &Config,
+    stats: &mut FilterStats,
+) -> Vec<&'a Notification> {
+    filter_notifications_since_with_stats(
+        notifications,
+        state_manager.get_last_checked_at(),
+        config,
+        stats,
+    )
+}
+
+/// 指定した日時より後に更新され、フィルタ条件を満たす通知を抽出（除外された件数を `stats` に記録）
+pub fn filter_notifications_since_with_stats<'a>(
+    notifications: &'a [Notification],
+    since: Option<&str>,
+    config: &Config,
+    stats: &mut FilterStats,
+) -> Vec<&'a Notification> {
+    let filtered_notifications: Vec<&'a Notification> = if let Some(last_checked) = since {
+        notifications
+            .iter()
+            .filter(|n| n.updated_at.as_str() > last_checked)
+            .collect()
+    } else {
+        // 最終確認日時がない場合はすべて新しいと見なす
+        notifications.iter().collect()
+    };
 
     // 設定に基づいて通知をフィルタリング
     filtered_notifications
         .into_iter()
-        .filter(|n| {
-            // Early exit if quick checks fail
-            // リポジトリプロパティのフィルタリング - これらのチェックは軽量なので先に行う
-            if config.notification_filters.exclude_private_repos && n.repository.private {
-                return false;
+        .filter(|n| match rejection_reason(n, config) {
+            Some(reason) => {
+                stats.record(reason);
+                false
             }
-
-            // 各フィルタを順に適用 (短絡評価により、いずれかがfalseなら以降は評価されない)
-            crate::polling::filters::repository_filter::filter_by_repository(n, config)
-                && crate::polling::filters::organization_filter::filter_by_organization(n, config)
-                && crate::polling::filters::type_filter::filter_by_subject_type(n, config)
-                && crate::polling::filters::reason_filter::filter_by_reason(n, config)
-                && crate::polling::filters::content_filter::filter_by_content(n, config)
-                && crate::polling::filters::time_filter::filter_by_time(n, config)
-                && crate::polling::filters::draft_filter::filter_by_draft_status(n, config)
+            None => true,
         })
         .collect()
 }
 
+/// 通知が設定されたフィルタ条件をすべて満たすかどうか（最終確認日時は考慮しない）
+pub fn matches_filters(n: &Notification, config: &Config) -> bool {
+    rejection_reason(n, config).is_none()
+}
+
+/// `auto_read_reasons` に一致し、表示せずに既読として扱う通知かどうか
+pub fn is_auto_read(n: &Notification, config: &Config) -> bool {
+    config
+        .notification_filters
+        .auto_read_reasons
+        .contains(&n.reason)
+}
+
+/// 個々のフィルタ関数（条件を満たす場合に true）
+type FilterFn = fn(&Notification, &Config) -> bool;
+
+/// 通知を除外したフィルタの名前を取得（すべての条件を満たす場合は `None`）
+///
+/// 理由（reason）によって除外された場合は通知の理由（例: "subscribed"）を返す
+pub fn rejection_reason(n: &Notification, config: &Config) -> Option<String> {
+    use crate::polling::filters::*;
+
+    // Early exit if quick checks fail
+    // リポジトリプロパティのフィルタリング - これらのチェックは軽量なので先に行う
+    if config.notification_filters.exclude_private_repos && n.repository.private {
+        return Some("private-repo".to_string());
+    }
+
+    // 各フィルタを順に適用し、最初に条件を満たさなかったフィルタを返す
+    let checks: [(&str, FilterFn); 8] = [
+        ("excluded-repo", repository_filter::filter_by_repository),
+        ("excluded-org", organization_filter::filter_by_organization),
+        ("subject-type", type_filter::filter_by_subject_type),
+        ("reason", reason_filter::filter_by_reason),
+        ("content", content_filter::filter_by_content),
+        ("too-old", time_filter::filter_by_time),
+        ("draft", draft_filter::filter_by_draft_status),
+        ("title", title_sanity_filter::filter_by_title_sanity),
+    ];
+
+    checks
+        .iter()
+        .find(|(_, check)| !check(n, config))
+        .map(|(name, _)| match *name {
+            "reason" => n.reason.clone(),
+            name => name.to_string(),
+        })
+}
+
+/// フィルタによって除外された通知の件数（除外理由ごと）
+#[derive(Debug, Default, Clone)]
+pub struct FilterStats {
+    counts: HashMap<String, usize>,
+    /// 判定に必要な情報（ラベルなど）を取得できず、判定せずに通知した件数（フィルタごと）
+    unverified: HashMap<String, usize>,
+}
+
+impl FilterStats {
+    pub fn record(&mut self, reason: String) {
+        *self.counts.entry(reason).or_insert(0) += 1;
+    }
+
+    /// 判定できずに通知したことを記録
+    pub fn record_unverified(&mut self, filter: &str) {
+        *self.unverified.entry(filter.to_string()).or_insert(0) += 1;
+    }
+
+    /// 判定できずに通知した件数
+    pub fn unverified_total(&self) -> usize {
+        self.unverified.values().sum()
+    }
+
+    /// 判定できずに通知した件数（件数の多い順）
+    pub fn unverified_breakdown(&self) -> Vec<(&str, usize)> {
+        let mut breakdown: Vec<(&str, usize)> = self
+            .unverified
+            .iter()
+            .map(|(filter, count)| (filter.as_str(), *count))
+            .collect();
+        breakdown.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        breakdown
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// 件数の多い順に除外理由を取得
+    pub fn breakdown(&self) -> Vec<(&str, usize)> {
+        let mut breakdown: Vec<(&str, usize)> = self
+            .counts
+            .iter()
+            .map(|(reason, count)| (reason.as_str(), *count))
+            .collect();
+        breakdown.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        breakdown
+    }
+
+    pub fn clear(&mut self) {
+        self.counts.clear();
+        self.unverified.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,6 +194,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "user/repo1".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -91,6 +217,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "user/repo2".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -101,8 +228,10 @@ mod tests {
         state_manager.update_last_checked_at(old_time.to_string());
 
         // Use a config with no filters to allow all notifications
-        let mut config = Config::default();
-        config.notification_filters = NotificationFilter::default();
+        let config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         let new_notifications = filter_new_notifications(&notifications, &state_manager, &config);
 
         assert_eq!(new_notifications.len(), 1);
@@ -133,6 +262,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "user/repo1".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -155,6 +285,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "user/repo2".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -164,9 +295,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at(old_time.to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         config
             .notification_filters
             .exclude_reasons
@@ -201,6 +334,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "user/repo1".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -223,6 +357,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "user/repo2".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -232,9 +367,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         config
             .notification_filters
             .include_repositories
@@ -269,6 +406,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "user/repo1".to_string(),
                     private: false, // Public repo
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -291,6 +429,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "user/repo2".to_string(),
                     private: true, // Private repo
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -300,9 +439,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         config.notification_filters.exclude_private_repos = true;
 
         let new_notifications = filter_new_notifications(&notifications, &state_manager, &config);
@@ -311,6 +452,75 @@ mod tests {
         assert_eq!(new_notifications[0].id, "1"); // Only the public repo notification
     }
 
+    #[test]
+    fn test_exclude_fork_repos_filter() {
+        let new_time = "2023-01-02T00:00:00Z";
+
+        let notifications = vec![
+            Notification {
+                id: "1".to_string(),
+                unread: true,
+                reason: "mention".to_string(),
+                updated_at: new_time.to_string(),
+                last_read_at: None,
+                subject: NotificationSubject {
+                    title: "Source repo notification".to_string(),
+                    url: Some("https://example.com/1".to_string()),
+                    latest_comment_url: None,
+                    kind: "Issue".to_string(),
+                },
+                repository: NotificationRepository {
+                    id: 1,
+                    node_id: "node1".to_string(),
+                    name: "repo1".to_string(),
+                    full_name: "user/repo1".to_string(),
+                    private: false,
+                    fork: false, // Source repo
+                },
+                url: "https://example.com/1".to_string(),
+                subscription_url: "https://example.com/subscription/1".to_string(),
+            },
+            Notification {
+                id: "2".to_string(),
+                unread: true,
+                reason: "comment".to_string(),
+                updated_at: new_time.to_string(),
+                last_read_at: None,
+                subject: NotificationSubject {
+                    title: "Fork notification".to_string(),
+                    url: Some("https://example.com/2".to_string()),
+                    latest_comment_url: None,
+                    kind: "Issue".to_string(),
+                },
+                repository: NotificationRepository {
+                    id: 2,
+                    node_id: "node2".to_string(),
+                    name: "repo2".to_string(),
+                    full_name: "user/repo2".to_string(),
+                    private: false,
+                    fork: true, // Forked repo
+                },
+                url: "https://example.com/2".to_string(),
+                subscription_url: "https://example.com/subscription/2".to_string(),
+            },
+        ];
+
+        let mut state_manager = StateManager::new().unwrap();
+        state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
+
+        // Reset notification filters to allow the test to work as expected
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
+        config.notification_filters.exclude_fork_repos = true;
+
+        let new_notifications = filter_new_notifications(&notifications, &state_manager, &config);
+
+        assert_eq!(new_notifications.len(), 1);
+        assert_eq!(new_notifications[0].id, "1"); // Only the source repo notification
+    }
+
     #[test]
     fn test_title_contains_filter() {
         let new_time = "2023-01-02T00:00:00Z";
@@ -334,6 +544,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "user/repo1".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -356,6 +567,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "user/repo2".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -365,9 +577,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         config
             .notification_filters
             .title_contains
@@ -407,6 +621,24 @@ mod tests {
         assert!(crate::polling::utils::parse_duration("invalid").is_err());
     }
 
+    #[test]
+    fn test_parse_timezone() {
+        use crate::polling::utils::parse_timezone;
+
+        assert_eq!(parse_timezone("local").unwrap(), None);
+        assert_eq!(parse_timezone("UTC").unwrap().unwrap().local_minus_utc(), 0);
+        assert_eq!(
+            parse_timezone("+09:00").unwrap().unwrap().local_minus_utc(),
+            9 * 3600
+        );
+        assert_eq!(
+            parse_timezone("-05:30").unwrap().unwrap().local_minus_utc(),
+            -(5 * 3600 + 30 * 60)
+        );
+        assert!(parse_timezone("Asia/Tokyo").is_err());
+        assert!(parse_timezone("+25:00").is_err());
+    }
+
     #[test]
     fn test_extract_org_name() {
         assert_eq!(crate::polling::utils::extract_org_name("org/repo"), "org");
@@ -440,6 +672,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "myorg/repo1".to_string(), // myorg
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -462,6 +695,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "otherorg/repo2".to_string(), // otherorg
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -471,9 +705,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         // Clear include filters so all notifications are considered
         config.notification_filters.include_reasons = vec![];
         config.notification_filters.include_subject_types = vec![];
@@ -511,6 +747,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "spamorg/repo1".to_string(), // spamorg
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -533,6 +770,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "goodorg/repo2".to_string(), // goodorg
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -542,9 +780,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         // Clear include filters so all notifications are considered
         config.notification_filters.include_reasons = vec![];
         config.notification_filters.include_subject_types = vec![];
@@ -582,6 +822,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "user/repo1".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -604,6 +845,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "user/repo2".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -613,9 +855,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         // Clear include filters so all notifications are considered
         config.notification_filters.include_reasons = vec![];
         config.notification_filters.include_subject_types = vec![];
@@ -653,6 +897,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "user/repo1".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -675,6 +920,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "user/repo2".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -684,9 +930,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         // Clear include filters so all notifications are considered
         config.notification_filters.include_reasons = vec![];
         config.notification_filters.include_subject_types = vec![];
@@ -724,6 +972,7 @@ mod tests {
                     name: "main-project".to_string(),
                     full_name: "user/main-project".to_string(), // contains "main"
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -746,6 +995,7 @@ mod tests {
                     name: "other-repo".to_string(),
                     full_name: "user/other-repo".to_string(), // does not contain "main"
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -755,9 +1005,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         // Clear include filters so all notifications are considered
         config.notification_filters.include_reasons = vec![];
         config.notification_filters.include_subject_types = vec![];
@@ -795,6 +1047,7 @@ mod tests {
                     name: "important-project".to_string(),
                     full_name: "user/important-project".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -817,6 +1070,7 @@ mod tests {
                     name: "other-project".to_string(),
                     full_name: "user/other-project".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -839,6 +1093,7 @@ mod tests {
                     name: "normal-project".to_string(),
                     full_name: "user/normal-project".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/3".to_string(),
                 subscription_url: "https://example.com/subscription/3".to_string(),
@@ -848,9 +1103,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         // For this test, we want to set specific include filters to test combination
         config.notification_filters.include_reasons = vec!["review_requested".to_string()];
         config.notification_filters.include_subject_types = vec!["PullRequest".to_string()];
@@ -885,6 +1142,7 @@ mod tests {
                     name: "repo1".to_string(),
                     full_name: "user/repo1".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/1".to_string(),
                 subscription_url: "https://example.com/subscription/1".to_string(),
@@ -907,6 +1165,7 @@ mod tests {
                     name: "repo2".to_string(),
                     full_name: "user/repo2".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/2".to_string(),
                 subscription_url: "https://example.com/subscription/2".to_string(),
@@ -929,6 +1188,7 @@ mod tests {
                     name: "repo3".to_string(),
                     full_name: "user/repo3".to_string(),
                     private: false,
+                    fork: false,
                 },
                 url: "https://example.com/3".to_string(),
                 subscription_url: "https://example.com/subscription/3".to_string(),
@@ -938,9 +1198,11 @@ mod tests {
         let mut state_manager = StateManager::new().unwrap();
         state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
 
-        let mut config = Config::default();
         // Reset notification filters to allow the test to work as expected
-        config.notification_filters = NotificationFilter::default();
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
         // Clear include filters so all notification types are considered
         config.notification_filters.include_reasons = vec![];
         config.notification_filters.include_subject_types = vec![];
@@ -956,4 +1218,187 @@ mod tests {
             assert_ne!(notification.id, "1"); // Draft PR should be excluded
         }
     }
+
+    #[test]
+    fn test_exclude_empty_titles_filter() {
+        let new_time = "2023-01-02T00:00:00Z";
+
+        let notifications = vec![
+            Notification {
+                id: "1".to_string(),
+                unread: true,
+                reason: "mention".to_string(),
+                updated_at: new_time.to_string(),
+                last_read_at: None,
+                subject: NotificationSubject {
+                    title: "   ".to_string(), // Empty (whitespace only) title
+                    url: Some("https://example.com/1".to_string()),
+                    latest_comment_url: None,
+                    kind: "Issue".to_string(),
+                },
+                repository: NotificationRepository {
+                    id: 1,
+                    node_id: "node1".to_string(),
+                    name: "repo1".to_string(),
+                    full_name: "user/repo1".to_string(),
+                    private: false,
+                    fork: false,
+                },
+                url: "https://example.com/1".to_string(),
+                subscription_url: "https://example.com/subscription/1".to_string(),
+            },
+            Notification {
+                id: "2".to_string(),
+                unread: true,
+                reason: "mention".to_string(),
+                updated_at: new_time.to_string(),
+                last_read_at: None,
+                subject: NotificationSubject {
+                    title: "(No Subject)".to_string(), // Placeholder title
+                    url: Some("https://example.com/2".to_string()),
+                    latest_comment_url: None,
+                    kind: "Issue".to_string(),
+                },
+                repository: NotificationRepository {
+                    id: 2,
+                    node_id: "node2".to_string(),
+                    name: "repo2".to_string(),
+                    full_name: "user/repo2".to_string(),
+                    private: false,
+                    fork: false,
+                },
+                url: "https://example.com/2".to_string(),
+                subscription_url: "https://example.com/subscription/2".to_string(),
+            },
+            Notification {
+                id: "3".to_string(),
+                unread: true,
+                reason: "mention".to_string(),
+                updated_at: new_time.to_string(),
+                last_read_at: None,
+                subject: NotificationSubject {
+                    title: "Regular notification (no subject)".to_string(), // Only partially matches
+                    url: Some("https://example.com/3".to_string()),
+                    latest_comment_url: None,
+                    kind: "Issue".to_string(),
+                },
+                repository: NotificationRepository {
+                    id: 3,
+                    node_id: "node3".to_string(),
+                    name: "repo3".to_string(),
+                    full_name: "user/repo3".to_string(),
+                    private: false,
+                    fork: false,
+                },
+                url: "https://example.com/3".to_string(),
+                subscription_url: "https://example.com/subscription/3".to_string(),
+            },
+        ];
+
+        let mut state_manager = StateManager::new().unwrap();
+        state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
+
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
+
+        // Disabled by default: all notifications pass
+        let new_notifications = filter_new_notifications(&notifications, &state_manager, &config);
+        assert_eq!(new_notifications.len(), 3);
+
+        // Enable the empty title and placeholder filters
+        config.notification_filters.exclude_empty_titles = true;
+        config.notification_filters.ignore_title_patterns = vec!["(no subject)".to_string()];
+
+        let new_notifications = filter_new_notifications(&notifications, &state_manager, &config);
+        assert_eq!(new_notifications.len(), 1);
+        assert_eq!(new_notifications[0].id, "3");
+    }
+
+    #[test]
+    fn test_fuzzy_repository_and_organization_filters() {
+        let notification = Notification {
+            id: "1".to_string(),
+            unread: true,
+            reason: "mention".to_string(),
+            updated_at: "2023-01-02T00:00:00Z".to_string(),
+            last_read_at: None,
+            subject: NotificationSubject {
+                title: "Fix login".to_string(),
+                url: Some("https://example.com/1".to_string()),
+                latest_comment_url: None,
+                kind: "Issue".to_string(),
+            },
+            repository: NotificationRepository {
+                id: 1,
+                node_id: "node1".to_string(),
+                name: "web-backend-service".to_string(),
+                full_name: "myorg/web-backend-service".to_string(),
+                private: false,
+                fork: false,
+            },
+            url: "https://example.com/1".to_string(),
+            subscription_url: "https://example.com/subscription/1".to_string(),
+        };
+
+        let mut config = Config {
+            notification_filters: NotificationFilter {
+                include_repositories: vec!["webbackend".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Exact matching by default: the abbreviated name does not match
+        assert!(!matches_filters(&notification, &config));
+
+        // Fuzzy hit
+        config.notification_filters.fuzzy_match_repositories = true;
+        assert!(matches_filters(&notification, &config));
+
+        // Deliberate non-match: characters do not appear in order
+        config.notification_filters.include_repositories = vec!["frontend".to_string()];
+        assert!(!matches_filters(&notification, &config));
+
+        // Organization lists are opt-in separately
+        config.notification_filters.include_repositories = vec![];
+        config.notification_filters.include_organizations = vec!["mo".to_string()];
+        assert!(!matches_filters(&notification, &config));
+        config.notification_filters.fuzzy_match_organizations = true;
+        assert!(matches_filters(&notification, &config));
+        config.notification_filters.exclude_organizations = vec!["otherorg".to_string()];
+        assert!(matches_filters(&notification, &config));
+    }
+
+    #[test]
+    fn test_fuzzy_matching_does_not_apply_to_exclude_lists() {
+        let notification = crate::test_support::notification("1", "myorg/web-backend-service");
+        let mut config = Config {
+            notification_filters: NotificationFilter {
+                include_repositories: vec!["webbackend".to_string()],
+                exclude_repositories: vec!["webbackend".to_string()],
+                fuzzy_match_repositories: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // The abbreviated name includes the repository fuzzily but does not exclude it
+        assert!(matches_filters(&notification, &config));
+
+        // Exact names in the exclude list still apply
+        config.notification_filters.exclude_repositories =
+            vec!["myorg/web-backend-service".to_string()];
+        assert!(!matches_filters(&notification, &config));
+
+        // Same for organizations
+        config.notification_filters.exclude_repositories = vec![];
+        config.notification_filters.include_organizations = vec!["mo".to_string()];
+        config.notification_filters.exclude_organizations = vec!["mo".to_string()];
+        config.notification_filters.fuzzy_match_organizations = true;
+        assert!(matches_filters(&notification, &config));
+        config.notification_filters.exclude_organizations = vec!["myorg".to_string()];
+        assert!(!matches_filters(&notification, &config));
+    }
 }
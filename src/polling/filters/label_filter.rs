@@ -0,0 +1,88 @@
+use crate::Config;
+
+/// ラベルのフィルタ（`include_labels` / `exclude_labels`）が設定されているかどうか
+///
+/// ラベルの照合には API の呼び出しが必要なため、設定されている場合のみラベルを取得する
+pub fn has_label_filters(config: &Config) -> bool {
+    !config.notification_filters.include_labels.is_empty()
+        || !config.notification_filters.exclude_labels.is_empty()
+}
+
+/// ラベルのフィルタの判定結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelDecision {
+    /// 条件を満たすため通知する
+    Keep,
+    /// 条件を満たさないため除外する
+    Reject,
+    /// ラベルを取得できなかったため、判定せずに通知する（除外された件数とは別に数える）
+    KeepUnverified,
+}
+
+/// 取得したラベル（取得に失敗した場合は `None`）からフィルタの判定を行う
+///
+/// ラベルを取得できなかった場合に除外すると通知を取りこぼすため、通知したうえで判定できなかったことを記録する
+pub fn decide_by_labels(labels: Option<&[String]>, config: &Config) -> LabelDecision {
+    match labels {
+        None => LabelDecision::KeepUnverified,
+        Some(labels) if filter_by_labels(labels, config) => LabelDecision::Keep,
+        Some(_) => LabelDecision::Reject,
+    }
+}
+
+/// Filters notifications based on the labels of their PR/Issue (labels are compared case-insensitively)
+pub fn filter_by_labels(labels: &[String], config: &Config) -> bool {
+    let has_label = |wanted: &String| labels.iter().any(|l| l.eq_ignore_ascii_case(wanted));
+
+    let include_labels = &config.notification_filters.include_labels;
+    if !include_labels.is_empty() && !include_labels.iter().any(has_label) {
+        return false;
+    }
+
+    !config
+        .notification_filters
+        .exclude_labels
+        .iter()
+        .any(has_label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NotificationFilter;
+
+    #[test]
+    fn test_filter_by_labels() {
+        let config = Config {
+            notification_filters: NotificationFilter {
+                include_labels: vec!["bug".to_string(), "security".to_string()],
+                exclude_labels: vec!["wontfix".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let labels = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        assert!(has_label_filters(&config));
+        assert!(filter_by_labels(&labels(&["Bug", "ui"]), &config));
+        assert!(!filter_by_labels(&labels(&["ui"]), &config));
+        assert!(!filter_by_labels(&labels(&[]), &config));
+        assert!(!filter_by_labels(&labels(&["bug", "wontfix"]), &config));
+
+        assert!(!has_label_filters(&Config::default()));
+
+        assert_eq!(
+            decide_by_labels(Some(&labels(&["bug"])), &config),
+            LabelDecision::Keep
+        );
+        assert_eq!(
+            decide_by_labels(Some(&labels(&["ui"])), &config),
+            LabelDecision::Reject
+        );
+        // ラベルを取得できなかった場合は除外しない
+        assert_eq!(
+            decide_by_labels(None, &config),
+            LabelDecision::KeepUnverified
+        );
+    }
+}
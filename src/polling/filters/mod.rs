@@ -1,7 +1,9 @@
 pub mod content_filter;
 pub mod draft_filter;
+pub mod label_filter;
 pub mod organization_filter;
 pub mod reason_filter;
 pub mod repository_filter;
 pub mod time_filter;
+pub mod title_sanity_filter;
 pub mod type_filter;
@@ -1,26 +1,97 @@
-use crate::polling::utils::extract_org_name;
+use crate::polling::utils::{extract_org_name, matches_any};
 use crate::{Config, Notification};
+use std::sync::RwLock;
+
+/// GitHub から取得した自分が所属する組織（`member_organizations_only`。取得前・取得に失敗した場合は `None`）
+static MEMBER_ORGANIZATIONS: RwLock<Option<Vec<String>>> = RwLock::new(None);
+
+/// 自分が所属する組織の一覧を設定（起動時と SIGHUP の受信時に更新する）
+pub fn set_member_organizations(organizations: Vec<String>) {
+    let organizations = organizations
+        .into_iter()
+        .map(|org| org.to_lowercase())
+        .collect();
+    *MEMBER_ORGANIZATIONS
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = Some(organizations);
+}
+
+/// `org_name` が自分が所属する組織かどうか（一覧を取得できていない場合は除外しないため true）
+fn is_member_organization(org_name: &str) -> bool {
+    MEMBER_ORGANIZATIONS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .is_none_or(|organizations| organizations.contains(&org_name.to_lowercase()))
+}
 
 /// Filters notifications based on organization inclusion/exclusion rules
 pub fn filter_by_organization(notification: &Notification, config: &Config) -> bool {
+    let filters = &config.notification_filters;
     let org_name = extract_org_name(&notification.repository.full_name);
 
-    if !config.notification_filters.include_organizations.is_empty()
-        && !config
-            .notification_filters
-            .include_organizations
-            .contains(&org_name)
+    if !filters.include_organizations.is_empty()
+        && !matches_any(
+            &filters.include_organizations,
+            &org_name,
+            filters.fuzzy_match_organizations,
+        )
     {
         return false;
     }
 
-    if config
-        .notification_filters
-        .exclude_organizations
-        .contains(&org_name)
+    // include_organizations が指定されている場合はその一覧を優先する
+    if filters.include_organizations.is_empty()
+        && filters.member_organizations_only
+        && !is_member_organization(&org_name)
     {
         return false;
     }
 
+    // 意図しない除外を防ぐため、exclude_organizations にはあいまい一致を使用しない
+    if matches_any(&filters.exclude_organizations, &org_name, false) {
+        return false;
+    }
+
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NotificationFilter;
+
+    fn create_test_notification(full_name: &str) -> Notification {
+        crate::test_support::notification("1", full_name)
+    }
+
+    #[test]
+    fn test_member_organizations_only() {
+        let config_with = |include_organizations: Vec<String>| Config {
+            notification_filters: NotificationFilter {
+                member_organizations_only: true,
+                include_organizations,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let config = config_with(Vec::new());
+        let member = create_test_notification("MyOrg/api");
+        let other = create_test_notification("other-org/app");
+
+        // 一覧を取得する前は除外しない
+        // （このテスト以外は member_organizations_only を有効にしないため、一覧の状態はこのテストでのみ変化する）
+        if MEMBER_ORGANIZATIONS.read().unwrap().is_none() {
+            assert!(filter_by_organization(&other, &config));
+        }
+
+        set_member_organizations(vec!["myorg".to_string()]);
+        assert!(filter_by_organization(&member, &config));
+        assert!(!filter_by_organization(&other, &config));
+
+        // include_organizations が指定されている場合はそちらを優先
+        let config = config_with(vec!["other-org".to_string()]);
+        assert!(filter_by_organization(&other, &config));
+        assert!(!filter_by_organization(&member, &config));
+    }
+}
@@ -1,24 +1,94 @@
 use crate::{Config, Notification};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+/// コンパイル済みの理由のパターン（パターン文字列 -> 正規表現）
+static REASON_PATTERNS: LazyLock<Mutex<HashMap<String, Regex>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
 /// Filters notifications based on reason inclusion/exclusion rules
 pub fn filter_by_reason(notification: &Notification, config: &Config) -> bool {
-    // 通知理由のフィルタリング
-    if !config.notification_filters.include_reasons.is_empty()
-        && !config
-            .notification_filters
-            .include_reasons
-            .contains(&notification.reason)
+    let filters = &config.notification_filters;
+
+    // 通知理由のフィルタリング（理由のリストと正規表現のどちらかに一致すれば含める）
+    if (!filters.include_reasons.is_empty() || !filters.include_reason_patterns.is_empty())
+        && !filters.include_reasons.contains(&notification.reason)
+        && !matches_any_pattern(&notification.reason, &filters.include_reason_patterns)
     {
         return false;
     }
 
-    if config
-        .notification_filters
-        .exclude_reasons
-        .contains(&notification.reason)
+    if filters.exclude_reasons.contains(&notification.reason)
+        || matches_any_pattern(&notification.reason, &filters.exclude_reason_patterns)
     {
         return false;
     }
 
     true
 }
+
+/// 理由のパターンを理由全体に一致する正規表現としてコンパイル
+pub fn compile_reason_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{})$", pattern))
+}
+
+/// `reason` がいずれかのパターンに一致するかどうか（不正なパターンは `load_config` で弾かれるため無視）
+fn matches_any_pattern(reason: &str, patterns: &[String]) -> bool {
+    if patterns.is_empty() {
+        return false;
+    }
+
+    let mut cache = REASON_PATTERNS.lock().unwrap_or_else(|e| e.into_inner());
+    patterns.iter().any(|pattern| {
+        if !cache.contains_key(pattern) {
+            match compile_reason_pattern(pattern) {
+                Ok(regex) => {
+                    cache.insert(pattern.clone(), regex);
+                }
+                Err(_) => return false,
+            }
+        }
+        cache[pattern].is_match(reason)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NotificationFilter;
+
+    fn create_test_notification(reason: &str) -> Notification {
+        Notification {
+            reason: reason.to_string(),
+            ..crate::test_support::notification("1", "user/repo")
+        }
+    }
+
+    fn config_with(notification_filters: NotificationFilter) -> Config {
+        Config {
+            notification_filters,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_filter_by_reason_patterns() {
+        let config = config_with(NotificationFilter {
+            include_reasons: vec!["assign".to_string()],
+            include_reason_patterns: vec![".*_mention".to_string(), "mention".to_string()],
+            exclude_reason_patterns: vec!["team_.*".to_string()],
+            ..Default::default()
+        });
+        let passes = |reason| filter_by_reason(&create_test_notification(reason), &config);
+
+        assert!(passes("assign"));
+        assert!(passes("mention"));
+        assert!(passes("author_mention"));
+        // 除外のパターンが優先
+        assert!(!passes("team_mention"));
+        // パターンは理由全体に一致する必要がある
+        assert!(!passes("mentioned"));
+        assert!(!passes("comment"));
+    }
+}
@@ -1,22 +1,30 @@
+use crate::polling::utils::matches_any;
 use crate::{Config, Notification};
 
 /// Filters notifications based on repository inclusion/exclusion rules
 pub fn filter_by_repository(notification: &Notification, config: &Config) -> bool {
+    let filters = &config.notification_filters;
+    let full_name = &notification.repository.full_name;
+
+    // フォークリポジトリの除外
+    if filters.exclude_fork_repos && notification.repository.fork {
+        return false;
+    }
+
     // include_repositoriesが指定されている場合、リストに含まれないリポジトリは除外
-    if !config.notification_filters.include_repositories.is_empty()
-        && !config
-            .notification_filters
-            .include_repositories
-            .contains(&notification.repository.full_name)
+    if !filters.include_repositories.is_empty()
+        && !matches_any(
+            &filters.include_repositories,
+            full_name,
+            filters.fuzzy_match_repositories,
+        )
     {
         return false;
     }
 
-    // exclude_repositoriesのチェック（既存のロジック）
-    for exclude_repo in &config.notification_filters.exclude_repositories {
-        if notification.repository.full_name == *exclude_repo {
-            return false;
-        }
+    // exclude_repositoriesのチェック（意図しない除外を防ぐため、あいまい一致は使用しない）
+    if matches_any(&filters.exclude_repositories, full_name, false) {
+        return false;
     }
 
     true
@@ -1,25 +1,116 @@
 use crate::polling::utils::parse_duration;
 use crate::polling::utils::parse_iso8601;
 use crate::{Config, Notification};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 解析済みの最小更新時間（設定値 -> 期間。解析できなかった場合は `None`）
+static MINIMUM_UPDATED_TIMES: LazyLock<Mutex<HashMap<String, Option<Duration>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
 /// Filters notifications based on time constraints
 pub fn filter_by_time(notification: &Notification, config: &Config) -> bool {
-    // 時間ベースのフィルタリング
-    if let Some(ref min_time_str) = config.notification_filters.minimum_updated_time
-        && let Ok(min_duration) = parse_duration(min_time_str)
-        && let Ok(updated_time) = parse_iso8601(&notification.updated_at)
-    {
-        let current_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        // 更新時刻が基準時刻より古い場合は除外
-        if updated_time < current_time - min_duration.as_secs() {
-            return false;
+    let current_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    filter_by_time_at(notification, config, current_time)
+}
+
+/// `current_time`（UNIX 時刻）を基準に時間ベースのフィルタリングを行う
+fn filter_by_time_at(notification: &Notification, config: &Config, current_time: u64) -> bool {
+    let Some(ref min_time_str) = config.notification_filters.minimum_updated_time else {
+        return true;
+    };
+    let Some(min_duration) = minimum_updated_duration(min_time_str) else {
+        return true;
+    };
+
+    // 更新時刻が基準時刻より古い場合は除外（更新時刻を解析できない場合は除外しない）
+    let cutoff = current_time.saturating_sub(min_duration.as_secs());
+    match parse_iso8601(&notification.updated_at) {
+        Ok(updated_time) => updated_time >= cutoff,
+        Err(_) => true,
+    }
+}
+
+/// 最小更新時間を解析（設定値ごとに1回だけ解析し、不正な値は警告を出してフィルタを無効にする）
+fn minimum_updated_duration(value: &str) -> Option<Duration> {
+    let mut cache = MINIMUM_UPDATED_TIMES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    *cache.entry(value.to_string()).or_insert_with(|| {
+        match parse_duration(value) {
+            // 空の値（0秒）は指定なしとして扱う
+            Ok(duration) if duration.is_zero() => None,
+            Ok(duration) => Some(duration),
+            Err(e) => {
+                tracing::warn!(
+                    "Invalid notification_filters.minimum_updated_time '{}': {}; time filter disabled",
+                    value,
+                    e
+                );
+                None
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NotificationFilter;
+
+    // 2023-01-02T00:00:00Z
+    const NOW: u64 = 1_672_617_600;
+
+    fn create_test_notification(updated_at: &str) -> Notification {
+        Notification {
+            updated_at: updated_at.to_string(),
+            ..crate::test_support::notification("1", "user/repo")
+        }
+    }
+
+    fn config_with(minimum_updated_time: Option<&str>) -> Config {
+        Config {
+            notification_filters: NotificationFilter {
+                minimum_updated_time: minimum_updated_time.map(str::to_string),
+                ..Default::default()
+            },
+            ..Default::default()
         }
     }
 
-    true
+    #[test]
+    fn test_minimum_updated_time_cutoff() {
+        let config = config_with(Some("1h"));
+
+        let recent = create_test_notification("2023-01-01T23:30:00Z");
+        let boundary = create_test_notification("2023-01-01T23:00:00Z");
+        let old = create_test_notification("2023-01-01T22:59:59Z");
+        assert!(filter_by_time_at(&recent, &config, NOW));
+        assert!(filter_by_time_at(&boundary, &config, NOW));
+        assert!(!filter_by_time_at(&old, &config, NOW));
+
+        // 更新時刻を解析できない通知は除外しない
+        let unparsable = create_test_notification("yesterday");
+        assert!(filter_by_time_at(&unparsable, &config, NOW));
+
+        // 未指定の場合は除外しない
+        assert!(filter_by_time_at(&old, &config_with(None), NOW));
+    }
+
+    #[test]
+    fn test_malformed_minimum_updated_time_disables_filter() {
+        let old = create_test_notification("2000-01-01T00:00:00Z");
+        for value in ["soon", "1x", "h", ""] {
+            assert!(filter_by_time_at(&old, &config_with(Some(value)), NOW));
+        }
+        assert_eq!(minimum_updated_duration("soon"), None);
+        assert_eq!(
+            minimum_updated_duration("2d"),
+            Some(Duration::from_secs(2 * 24 * 60 * 60))
+        );
+    }
 }
@@ -0,0 +1,20 @@
+use crate::{Config, Notification};
+
+/// Filters out notifications with degenerate titles (empty or placeholder titles)
+pub fn filter_by_title_sanity(notification: &Notification, config: &Config) -> bool {
+    let title = notification.subject.title.trim();
+
+    // 空タイトルのフィルタリング
+    if config.notification_filters.exclude_empty_titles && title.is_empty() {
+        return false;
+    }
+
+    // プレースホルダータイトルのフィルタリング（タイトル全体が一致する場合のみ）
+    for pattern in &config.notification_filters.ignore_title_patterns {
+        if title.eq_ignore_ascii_case(pattern.trim()) {
+            return false;
+        }
+    }
+
+    true
+}
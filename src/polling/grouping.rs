@@ -0,0 +1,167 @@
+use crate::config::GroupBy;
+use crate::polling::urls::notifications_page_url;
+use crate::polling::utils::extract_org_name;
+use crate::{Config, Notification};
+
+/// 通知が属するグループのキー（`GroupBy::None` の場合は `None`）
+pub fn group_key(notification: &Notification, group_by: GroupBy) -> Option<String> {
+    match group_by {
+        GroupBy::None => None,
+        GroupBy::Repository => Some(notification.repository.full_name.clone()),
+        GroupBy::Organization => Some(extract_org_name(&notification.repository.full_name)),
+        GroupBy::Reason => Some(notification.reason.clone()),
+    }
+}
+
+/// バッチの通知をまとめる単位（`notification.group_by` が none の場合は `notification_batch_config.group_by_repository` に従う）
+pub fn batch_group_by(config: &Config) -> GroupBy {
+    match config.notification.group_by {
+        GroupBy::None if config.notification_batch_config.group_by_repository => {
+            GroupBy::Repository
+        }
+        group_by => group_by,
+    }
+}
+
+/// 通知をグループごとにまとめる（グループは最初に現れた順、グループ内は元の順序）
+///
+/// `GroupBy::None` の場合は通知ごとに 1 つのグループ（キーは空文字列）になる
+pub fn group_notifications<'a>(
+    notifications: impl IntoIterator<Item = &'a Notification>,
+    group_by: GroupBy,
+) -> Vec<(String, Vec<&'a Notification>)> {
+    let mut groups: Vec<(String, Vec<&'a Notification>)> = Vec::new();
+    for notification in notifications {
+        let Some(key) = group_key(notification, group_by) else {
+            groups.push((String::new(), vec![notification]));
+            continue;
+        };
+        match groups.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, members)) => members.push(notification),
+            None => groups.push((key, vec![notification])),
+        }
+    }
+    groups
+}
+
+/// グループの通知を GitHub の通知一覧で絞り込んで開く URL（`web_base` は Web のページのベース URL）
+pub fn group_url(key: &str, group_by: GroupBy, web_base: &str) -> String {
+    let page = notifications_page_url(web_base);
+    let qualifier = match group_by {
+        GroupBy::None => return page,
+        GroupBy::Repository => "repo",
+        GroupBy::Organization => "org",
+        GroupBy::Reason => "reason",
+    };
+    format!("{}?query={}:{}", page, qualifier, key)
+}
+
+/// ダイジェストの上位グループの見出し
+pub fn group_label(group_by: GroupBy) -> &'static str {
+    match group_by {
+        GroupBy::None | GroupBy::Repository => "repositories",
+        GroupBy::Organization => "organizations",
+        GroupBy::Reason => "reasons",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_notification(id: &str, full_name: &str, reason: &str) -> Notification {
+        Notification {
+            reason: reason.to_string(),
+            ..crate::test_support::notification(id, full_name)
+        }
+    }
+
+    fn buckets(notifications: &[Notification], group_by: GroupBy) -> Vec<(String, Vec<&str>)> {
+        group_notifications(notifications, group_by)
+            .into_iter()
+            .map(|(key, members)| (key, members.iter().map(|n| n.id.as_str()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_group_notifications_by_key() {
+        let notifications = [
+            create_test_notification("1", "org/api", "mention"),
+            create_test_notification("2", "org/web", "review_requested"),
+            create_test_notification("3", "other/lib", "mention"),
+            create_test_notification("4", "org/api", "review_requested"),
+        ];
+
+        assert_eq!(
+            buckets(&notifications, GroupBy::Repository),
+            vec![
+                ("org/api".to_string(), vec!["1", "4"]),
+                ("org/web".to_string(), vec!["2"]),
+                ("other/lib".to_string(), vec!["3"]),
+            ]
+        );
+        assert_eq!(
+            buckets(&notifications, GroupBy::Organization),
+            vec![
+                ("org".to_string(), vec!["1", "2", "4"]),
+                ("other".to_string(), vec!["3"]),
+            ]
+        );
+        assert_eq!(
+            buckets(&notifications, GroupBy::Reason),
+            vec![
+                ("mention".to_string(), vec!["1", "3"]),
+                ("review_requested".to_string(), vec!["2", "4"]),
+            ]
+        );
+        // none はまとめずに元の順序のまま
+        assert_eq!(
+            buckets(&notifications, GroupBy::None),
+            vec![
+                (String::new(), vec!["1"]),
+                (String::new(), vec!["2"]),
+                (String::new(), vec!["3"]),
+                (String::new(), vec!["4"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_group_by() {
+        let mut config = Config::default();
+        assert_eq!(batch_group_by(&config), GroupBy::None);
+
+        config.notification_batch_config.group_by_repository = true;
+        assert_eq!(batch_group_by(&config), GroupBy::Repository);
+
+        // notification.group_by が指定されている場合はそちらを優先
+        config.notification.group_by = GroupBy::Reason;
+        assert_eq!(batch_group_by(&config), GroupBy::Reason);
+    }
+
+    #[test]
+    fn test_group_url() {
+        assert_eq!(
+            group_url("org/api", GroupBy::Repository, "https://github.com"),
+            "https://github.com/notifications?query=repo:org/api"
+        );
+        assert_eq!(
+            group_url("mention", GroupBy::Reason, "https://github.com"),
+            "https://github.com/notifications?query=reason:mention"
+        );
+        assert_eq!(
+            group_url("", GroupBy::None, "https://github.com"),
+            "https://github.com/notifications"
+        );
+
+        // GitHub Enterprise Server
+        assert_eq!(
+            group_url("org/api", GroupBy::Repository, "https://ghe.example.com"),
+            "https://ghe.example.com/notifications?query=repo:org/api"
+        );
+        assert_eq!(
+            group_url("", GroupBy::None, "https://ghe.example.com"),
+            "https://ghe.example.com/notifications"
+        );
+    }
+}
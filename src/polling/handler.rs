@@ -1,13 +1,148 @@
+use crate::config::{DisplayConfig, TimeFormat};
 use crate::poller::Notifier;
-use crate::{GitHubClient, Notification};
+use crate::polling::popup_gate::PopupGate;
+use crate::polling::read_queue::{ReadQueue, mark_all_as_read_or_enqueue, mark_as_read_or_enqueue};
+use crate::polling::template::{self, TemplateValues};
+use crate::polling::utils::parse_timezone;
+use crate::{Config, GitHubClient, Notification};
 use chrono::{DateTime, Local, Utc};
 
-/// 通知を Notifier に渡して表示し、必要に応じて既読にする
+/// 通知を Notifier に渡して表示し、必要に応じて既読にする（失敗した場合は `read_queue` に追加）
+///
+/// `gate` が表示を抑制する場合（おやすみモード・静かな時間帯・スレッドのクールダウン）はデスクトップ通知のみを表示しない。
+/// 表示に失敗した場合もエラーを返す前に既読にする
 pub async fn handle_notification(
     notification: &Notification,
     notifier: &dyn Notifier,
     github_client: &mut GitHubClient,
-    mark_as_read_on_notify: bool,
+    config: &Config,
+    read_queue: &mut ReadQueue,
+    gate: &mut PopupGate<'_>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let result = if gate.is_suppressed() || gate.in_cooldown(notification) {
+        Ok(())
+    } else {
+        let result = show_notification(notification, notifier, config);
+        if result.is_ok() {
+            gate.record_shown(notification);
+        }
+        result
+    };
+
+    if config.mark_as_read_on_notify {
+        mark_as_read_or_enqueue(github_client, read_queue, &notification.id).await;
+    }
+
+    result
+}
+
+/// 複数の通知を順に表示し、既読にする処理を同時に最大 `polling.max_concurrent` 件で行う
+///
+/// 表示に失敗した通知やクールダウン中のスレッドの通知も含め、渡されたすべての通知を既読にする
+/// （既読にできなかった通知は `read_queue` に追加）。
+/// 表示できた（おやすみモード・静かな時間帯の場合は表示を抑制した）通知を `notifications` と同じ順で返す
+pub async fn handle_notifications<'a>(
+    notifications: &[&'a Notification],
+    notifier: &dyn Notifier,
+    github_client: &mut GitHubClient,
+    config: &Config,
+    read_queue: &mut ReadQueue,
+    gate: &mut PopupGate<'_>,
+) -> Vec<&'a Notification> {
+    if gate.is_suppressed() {
+        tracing::debug!(
+            count = notifications.len(),
+            "Desktop notifications are suppressed, skipping popups"
+        );
+    }
+    let mut shown = Vec::with_capacity(notifications.len());
+    for &notification in notifications {
+        if gate.is_suppressed() {
+            shown.push(notification);
+            continue;
+        }
+        if gate.in_cooldown(notification) {
+            continue;
+        }
+        match show_notification(notification, notifier, config) {
+            Ok(()) => {
+                gate.record_shown(notification);
+                shown.push(notification);
+            }
+            Err(e) => tracing::error!("Failed to handle notification: {}", e),
+        }
+    }
+
+    if config.mark_as_read_on_notify {
+        let ids: Vec<String> = notifications.iter().map(|n| n.id.clone()).collect();
+        mark_all_as_read_or_enqueue(
+            github_client,
+            read_queue,
+            &ids,
+            config.polling.max_concurrent,
+        )
+        .await;
+    }
+
+    shown
+}
+
+/// 通知を Webhook・Slack に同時に最大 `polling.max_concurrent` 件で送信
+///
+/// デスクトップ通知の送信モードやおやすみモード、静かな時間帯に関係なく、渡されたすべての通知を送信する
+pub async fn post_notifications(notifications: &[&Notification], config: &Config) {
+    if notifications.is_empty() || !(config.webhook.enabled || config.notification.slack.enabled) {
+        return;
+    }
+
+    let (webhook, slack) = (config.webhook.clone(), config.notification.slack.clone());
+    // 送信先ごとに `[github]` の TLS 設定を反映したクライアントを作成（作成できない送信先には送信しない）
+    let webhook_client = outbound_client(webhook.enabled, "webhook", || {
+        crate::webhook::build_client(&config.github)
+    });
+    let slack_client = outbound_client(slack.enabled, "Slack", || {
+        crate::slack::build_client(&config.github)
+    });
+    let web_base = config.github.web_base_url();
+    let notifications: Vec<Notification> = notifications.iter().map(|n| (*n).clone()).collect();
+    crate::concurrency::run_bounded(
+        notifications,
+        config.polling.max_concurrent,
+        |notification| {
+            let (webhook, slack, web_base) = (webhook.clone(), slack.clone(), web_base.clone());
+            let (webhook_client, slack_client) = (webhook_client.clone(), slack_client.clone());
+            async move {
+                if let Some(client) = &webhook_client {
+                    crate::webhook::post_notification(client, &webhook, &notification).await;
+                }
+                if let Some(client) = &slack_client {
+                    crate::slack::post_notification(client, &slack, &web_base, &notification).await;
+                }
+            }
+        },
+    )
+    .await;
+}
+
+/// 有効な送信先の HTTP クライアントを作成（作成に失敗した場合はログに出力して `None`）
+fn outbound_client(
+    enabled: bool,
+    name: &str,
+    build: impl FnOnce() -> Result<reqwest::Client, crate::AuthError>,
+) -> Option<reqwest::Client> {
+    if !enabled {
+        return None;
+    }
+    build()
+        .inspect_err(|e| tracing::warn!("Failed to create the {} HTTP client: {}", name, e))
+        .ok()
+}
+
+/// 通知のタイトルと本文を組み立ててデスクトップに表示（Slack がデスクトップ通知を置き換える場合は表示しない）
+fn show_notification(
+    notification: &Notification,
+    notifier: &dyn Notifier,
+    config: &Config,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // Create a more specific title with reason information
     let reason_text = get_reason_display_text(&notification.reason);
@@ -16,35 +151,132 @@ pub async fn handle_notification(
     } else {
         notification.repository.full_name.clone()
     };
-    let title = format!("{} - {}", repo_name, reason_text);
 
     // Create a more informative body with additional context
-    let time_ago_text = format_time_ago(&notification.updated_at);
-    let url = &notification
-        .subject
-        .url
-        .as_ref()
-        .unwrap_or(&notification.url);
-    let body = format!(
-        "{}\n\n{} | {} | Updated: {}\nURL: {}",
-        notification.subject.title,
-        notification.repository.name,
-        format_subject_kind(&notification.subject.kind),
-        time_ago_text,
-        url
-    );
-
-    notifier.send_notification(&title, &body, url)?;
-
-    if mark_as_read_on_notify {
-        github_client
-            .mark_notification_as_read(&notification.id)
-            .await?;
-    }
+    let time_ago_text = format_timestamp(&notification.updated_at, &config.display);
+    let web_base = config.github.web_base_url();
+    let url = &crate::polling::urls::open_url(notification, &web_base);
+    let kind = format_subject_kind(&notification.subject.kind);
+
+    let values = TemplateValues {
+        repo: &repo_name,
+        reason: &reason_text,
+        title: &notification.subject.title,
+        kind: &kind,
+        age: &time_ago_text,
+        url,
+    };
+    let title = match &config.notification.title_template {
+        Some(template) => template::render(template, &values),
+        None => format!("{} - {}", repo_name, reason_text),
+    };
+    let body = match &config.notification.body_template {
+        Some(template) => template::render(template, &values),
+        None => format!(
+            "{}\n\n{} | {} | Updated: {}\nURL: {}",
+            notification.subject.title, notification.repository.name, kind, time_ago_text, url
+        ),
+    };
 
+    let body = crate::polling::body::format_popup_body(&body, &config.notification);
+
+    let priority = crate::polling::priority::priority_for(notification, &config.notification);
+    let timeout = crate::polling::priority::timeout_for(priority, &config.notification);
+    let sound = crate::polling::priority::should_play_sound(priority, &config.notification);
+    if !replaces_desktop(config) {
+        notifier.send_notification_with_sound(&title, &body, url, timeout, sound)?;
+    }
     Ok(())
 }
 
+/// グループの件数が多い場合に本文に表示するタイトルの最大数
+const GROUP_BODY_MAX_TITLES: usize = 5;
+
+/// 同じグループ（`notification.group_by`、`notification_batch_config.group_by_repository`）の複数の通知を 1 件のデスクトップ通知にまとめて表示
+///
+/// クールダウン中のスレッドの通知はまとめた表示から除き、表示に失敗した場合もエラーを返す前に
+/// グループ内のすべての通知を既読にする
+pub async fn handle_notification_group(
+    key: &str,
+    notifications: &[&Notification],
+    notifier: &dyn Notifier,
+    github_client: &mut GitHubClient,
+    config: &Config,
+    read_queue: &mut ReadQueue,
+    gate: &mut PopupGate<'_>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let popup: Vec<&Notification> = if gate.is_suppressed() {
+        Vec::new()
+    } else {
+        notifications
+            .iter()
+            .copied()
+            .filter(|n| !gate.in_cooldown(n))
+            .collect()
+    };
+    let result = if popup.is_empty() || replaces_desktop(config) {
+        Ok(())
+    } else {
+        let result = show_notification_group(key, &popup, notifier, config);
+        if result.is_ok() {
+            for notification in &popup {
+                gate.record_shown(notification);
+            }
+        }
+        result
+    };
+
+    if config.mark_as_read_on_notify {
+        for notification in notifications {
+            mark_as_read_or_enqueue(github_client, read_queue, &notification.id).await;
+        }
+    }
+
+    result
+}
+
+/// グループの通知を 1 件のデスクトップ通知にまとめて表示
+fn show_notification_group(
+    key: &str,
+    notifications: &[&Notification],
+    notifier: &dyn Notifier,
+    config: &Config,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let group_by = crate::polling::grouping::batch_group_by(config);
+    let title = format!("{} - {} notifications", key, notifications.len());
+
+    let mut lines: Vec<String> = notifications
+        .iter()
+        .take(GROUP_BODY_MAX_TITLES)
+        .map(|n| format!("• {} ({})", n.subject.title, n.repository.name))
+        .collect();
+    if notifications.len() > GROUP_BODY_MAX_TITLES {
+        lines.push(format!(
+            "and {} more",
+            notifications.len() - GROUP_BODY_MAX_TITLES
+        ));
+    }
+    let body = crate::polling::body::format_popup_body(&lines.join("\n"), &config.notification);
+
+    // グループ内で最も高い優先度に合わせて表示する
+    let priority = notifications
+        .iter()
+        .map(|n| crate::polling::priority::priority_for(n, &config.notification))
+        .max()
+        .unwrap_or(crate::polling::priority::NotificationPriority::Normal);
+    let timeout = crate::polling::priority::timeout_for(priority, &config.notification);
+    let sound = crate::polling::priority::should_play_sound(priority, &config.notification);
+    let web_base = config.github.web_base_url();
+    let url = crate::polling::grouping::group_url(key, group_by, &web_base);
+    notifier.send_notification_with_sound(&title, &body, &url, timeout, sound)
+}
+
+/// Slack への投稿がデスクトップ通知の代わりになるかどうか（`notification.slack.replace_desktop`）
+fn replaces_desktop(config: &Config) -> bool {
+    let slack = &config.notification.slack;
+    slack.enabled && slack.replace_desktop
+}
+
 /// Get a user-friendly display text for notification reasons
 fn get_reason_display_text(reason: &str) -> String {
     match reason {
@@ -74,34 +306,56 @@ fn format_subject_kind(kind: &str) -> String {
     }
 }
 
-/// Format time to show how long ago the notification was updated
-fn format_time_ago(updated_at: &str) -> String {
+/// Format a notification timestamp for display based on the display settings
+/// (timestamps are stored and received in UTC, only the output is converted)
+fn format_timestamp(timestamp: &str, display: &DisplayConfig) -> String {
     // Parse the ISO 8601 timestamp from GitHub API
-    match DateTime::parse_from_rfc3339(updated_at) {
-        Ok(updated_time) => {
-            let utc_time: DateTime<Utc> =
-                DateTime::from_naive_utc_and_offset(updated_time.naive_utc(), Utc);
-
-            let local_time: DateTime<Local> = DateTime::<Local>::from(utc_time);
-
-            let now = Local::now();
-            let duration = now.signed_duration_since(local_time);
-
-            // Format based on duration
-            if duration.num_seconds() < 60 {
-                "just now".to_string()
-            } else if duration.num_minutes() < 60 {
-                format!("{}m ago", duration.num_minutes())
-            } else if duration.num_hours() < 24 {
-                format!("{}h ago", duration.num_hours())
-            } else if duration.num_days() < 7 {
-                format!("{}d ago", duration.num_days())
-            } else {
-                // Show date if older than a week
-                local_time.format("%b %d").to_string()
+    match DateTime::parse_from_rfc3339(timestamp) {
+        Ok(parsed) => {
+            let utc_time = parsed.with_timezone(&Utc);
+            match display.time_format {
+                TimeFormat::Relative => format_time_ago(utc_time, Utc::now(), display),
+                TimeFormat::Absolute => format_in_timezone(utc_time, display, "%Y-%m-%d %H:%M %:z"),
             }
         }
-        Err(_) => updated_at.to_string(), // Fallback to original string if parsing fails
+        Err(_) => timestamp.to_string(), // Fallback to original string if parsing fails
+    }
+}
+
+/// Format a UTC time in the configured display timezone (system local by default)
+fn format_in_timezone(time: DateTime<Utc>, display: &DisplayConfig, fmt: &str) -> String {
+    let offset = display
+        .timezone
+        .as_deref()
+        .and_then(|tz| parse_timezone(tz).ok())
+        .flatten();
+
+    match offset {
+        Some(offset) => time.with_timezone(&offset).format(fmt).to_string(),
+        None => time.with_timezone(&Local).format(fmt).to_string(),
+    }
+}
+
+/// Format time to show how long ago the notification was updated
+fn format_time_ago(
+    updated_time: DateTime<Utc>,
+    now: DateTime<Utc>,
+    display: &DisplayConfig,
+) -> String {
+    let duration = now.signed_duration_since(updated_time);
+
+    // Format based on duration
+    if duration.num_seconds() < 60 {
+        "just now".to_string()
+    } else if duration.num_minutes() < 60 {
+        format!("{}m ago", duration.num_minutes())
+    } else if duration.num_hours() < 24 {
+        format!("{}h ago", duration.num_hours())
+    } else if duration.num_days() < 7 {
+        format!("{}d ago", duration.num_days())
+    } else {
+        // Show date if older than a week
+        format_in_timezone(updated_time, display, "%b %d")
     }
 }
 
@@ -126,7 +380,7 @@ mod tests {
     #[tokio::test]
     #[ignore] // 認証トークンがないとテストできないため
     async fn test_handle_notification() {
-        let _config = Config::default();
+        let config = Config::default();
         let auth_manager = AuthManager::new().unwrap();
         let mut github_client = GitHubClient::new(auth_manager).unwrap();
         let notification = Notification {
@@ -147,13 +401,149 @@ mod tests {
                 name: "repo1".to_string(),
                 full_name: "user/repo1".to_string(),
                 private: false,
+                fork: false,
             },
             url: "https://example.com/1".to_string(),
             subscription_url: "https://example.com/subscription/1".to_string(),
         };
         let notifier: &dyn crate::poller::Notifier = &DummyNotifier;
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let mut state_manager =
+            crate::StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        let mut gate = PopupGate::new(&mut state_manager, &config, false, Utc::now());
 
-        let result = handle_notification(&notification, notifier, &mut github_client, false).await;
+        let result = handle_notification(
+            &notification,
+            notifier,
+            &mut github_client,
+            &config,
+            &mut ReadQueue::default(),
+            &mut gate,
+        )
+        .await;
         assert!(result.is_ok());
     }
+
+    /// 表示した通知のタイトルを記録する Notifier
+    struct RecordingNotifier {
+        titles: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl crate::poller::Notifier for RecordingNotifier {
+        fn send_notification(
+            &self,
+            title: &str,
+            _body: &str,
+            _url: &str,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.titles.lock().unwrap().push(title.to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_notifications_handles_all() {
+        let mut config = Config {
+            mark_as_read_on_notify: true,
+            ..Default::default()
+        };
+        config.polling.max_concurrent = 2;
+        config.notification.title_template = Some("{title}".to_string());
+        // トークンがないため既読にする処理はすべて失敗する
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier {
+            titles: std::sync::Mutex::new(Vec::new()),
+        };
+        let mut read_queue = ReadQueue::default();
+
+        let notifications: Vec<Notification> = (1..=5)
+            .map(|i| {
+                let mut notification =
+                    crate::test_support::notification(&i.to_string(), "user/repo1");
+                notification.subject.title = i.to_string();
+                notification
+            })
+            .collect();
+        let refs: Vec<&Notification> = notifications.iter().collect();
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let mut state_manager =
+            crate::StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        let mut gate = PopupGate::new(&mut state_manager, &config, false, Utc::now());
+
+        let shown = handle_notifications(
+            &refs,
+            &notifier,
+            &mut github_client,
+            &config,
+            &mut read_queue,
+            &mut gate,
+        )
+        .await;
+
+        // すべての通知を順に表示し、既読にできなかった通知をすべて再試行のキューに追加する
+        let ids: Vec<String> = (1..=5).map(|i| i.to_string()).collect();
+        assert_eq!(shown.iter().map(|n| n.id.clone()).collect::<Vec<_>>(), ids);
+        assert_eq!(*notifier.titles.lock().unwrap(), ids);
+        assert_eq!(read_queue.thread_ids(), ids);
+    }
+
+    #[test]
+    fn test_format_timestamp_absolute_fixed_offset() {
+        let display = DisplayConfig {
+            time_format: TimeFormat::Absolute,
+            timezone: Some("+09:00".to_string()),
+        };
+        assert_eq!(
+            format_timestamp("2023-01-01T12:00:00Z", &display),
+            "2023-01-01 21:00 +09:00"
+        );
+
+        let display = DisplayConfig {
+            time_format: TimeFormat::Absolute,
+            timezone: Some("-05:30".to_string()),
+        };
+        assert_eq!(
+            format_timestamp("2023-01-01T03:00:00Z", &display),
+            "2022-12-31 21:30 -05:30"
+        );
+
+        let display = DisplayConfig {
+            time_format: TimeFormat::Absolute,
+            timezone: Some("utc".to_string()),
+        };
+        assert_eq!(
+            format_timestamp("2023-01-01T12:00:00Z", &display),
+            "2023-01-01 12:00 +00:00"
+        );
+    }
+
+    #[test]
+    fn test_format_time_ago() {
+        let display = DisplayConfig {
+            time_format: TimeFormat::Relative,
+            timezone: Some("+09:00".to_string()),
+        };
+        let updated = DateTime::parse_from_rfc3339("2023-01-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let now = updated + chrono::Duration::minutes(5);
+        assert_eq!(format_time_ago(updated, now, &display), "5m ago");
+
+        let now = updated + chrono::Duration::hours(3);
+        assert_eq!(format_time_ago(updated, now, &display), "3h ago");
+
+        // 1週間以上前の場合は設定されたタイムゾーンでの日付を表示
+        let updated = DateTime::parse_from_rfc3339("2023-01-01T20:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let now = updated + chrono::Duration::days(10);
+        assert_eq!(format_time_ago(updated, now, &display), "Jan 02");
+    }
+
+    #[test]
+    fn test_format_timestamp_invalid() {
+        let display = DisplayConfig::default();
+        assert_eq!(format_timestamp("not a date", &display), "not a date");
+    }
 }
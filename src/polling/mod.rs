@@ -1,7 +1,17 @@
+pub mod active_hours;
+pub mod backoff;
+pub mod body;
 pub mod filter;
 pub mod filters;
+pub mod grouping;
 pub mod handler;
+pub mod popup_gate;
+pub mod priority;
+pub mod read_queue;
 pub mod runner;
+pub mod schedule;
+pub mod template;
+pub mod urls;
 pub mod utils;
 
 pub use filter::filter_new_notifications;
@@ -0,0 +1,125 @@
+use crate::{Config, Notification, StateManager};
+use chrono::{DateTime, Utc};
+
+/// デスクトップ通知を表示する直前に、表示するかどうかを判定する
+///
+/// おやすみモード・静かな時間帯の間はすべての表示を抑制し、`thread_cooldown_sec` 以内に表示したスレッドは
+/// 表示しない。Webhook・Slack への送信や既読にする処理には影響しない
+pub struct PopupGate<'a> {
+    suppressed: bool,
+    state_manager: &'a mut StateManager,
+    /// この日時より後に表示したスレッドはクールダウン中（`thread_cooldown_sec` が0の場合は `None`）
+    cooldown_start: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+}
+
+impl<'a> PopupGate<'a> {
+    /// `suppressed` はおやすみモード・静かな時間帯で表示をすべて抑制するかどうか
+    pub fn new(
+        state_manager: &'a mut StateManager,
+        config: &Config,
+        suppressed: bool,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let cooldown_sec = config.notification.thread_cooldown_sec;
+        let cooldown_start = (cooldown_sec > 0).then(|| {
+            let cooldown =
+                chrono::Duration::seconds(i64::try_from(cooldown_sec).unwrap_or(i64::MAX));
+            now.checked_sub_signed(cooldown).unwrap_or(now)
+        });
+        // クールダウンを過ぎた記録は不要なので削除
+        if let Some(cooldown_start) = cooldown_start {
+            state_manager.prune_thread_dispatched_before(cooldown_start);
+        }
+        PopupGate {
+            suppressed,
+            state_manager,
+            cooldown_start,
+            now,
+        }
+    }
+
+    /// おやすみモード・静かな時間帯で表示をすべて抑制するかどうか
+    pub fn is_suppressed(&self) -> bool {
+        self.suppressed
+    }
+
+    /// 通知のスレッドを `thread_cooldown_sec` 以内に表示したかどうか
+    pub fn in_cooldown(&self, notification: &Notification) -> bool {
+        let Some(cooldown_start) = self.cooldown_start else {
+            return false;
+        };
+        let in_cooldown = self
+            .state_manager
+            .get_thread_dispatched_at(&notification.id)
+            .is_some_and(|at| at > cooldown_start);
+        if in_cooldown {
+            tracing::debug!(
+                id = %notification.id,
+                "Thread is in cooldown, skipping desktop notification"
+            );
+        }
+        in_cooldown
+    }
+
+    /// 通知を表示できたスレッドの日時を記録
+    pub fn record_shown(&mut self, notification: &Notification) {
+        if self.cooldown_start.is_some() {
+            self.state_manager
+                .record_thread_dispatched(notification.id.clone(), self.now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thread_cooldown() {
+        let mut config = Config::default();
+        config.notification.thread_cooldown_sec = 300;
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let mut state_manager =
+            StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        let notification = crate::test_support::notification("1", "user/repo1");
+        let now = Utc::now();
+
+        // Not in cooldown until a popup has been recorded
+        let mut gate = PopupGate::new(&mut state_manager, &config, false, now);
+        assert!(!gate.in_cooldown(&notification));
+        gate.record_shown(&notification);
+        assert!(gate.in_cooldown(&notification));
+
+        let gate = PopupGate::new(
+            &mut state_manager,
+            &config,
+            false,
+            now + chrono::Duration::seconds(60),
+        );
+        assert!(gate.in_cooldown(&notification));
+
+        // Once the cooldown has passed the thread pops up again
+        let gate = PopupGate::new(
+            &mut state_manager,
+            &config,
+            false,
+            now + chrono::Duration::seconds(301),
+        );
+        assert!(!gate.in_cooldown(&notification));
+    }
+
+    #[test]
+    fn test_cooldown_disabled() {
+        let config = Config::default();
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let mut state_manager =
+            StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        let notification = crate::test_support::notification("1", "user/repo1");
+
+        let mut gate = PopupGate::new(&mut state_manager, &config, false, Utc::now());
+        gate.record_shown(&notification);
+        assert!(!gate.in_cooldown(&notification));
+        assert!(state_manager.state.thread_dispatched_at.is_empty());
+    }
+}
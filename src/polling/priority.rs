@@ -0,0 +1,135 @@
+use crate::Notification;
+use crate::config::NotificationConfig;
+use crate::poller::NotificationTimeout;
+use serde::{Deserialize, Serialize};
+
+/// 通知の優先度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationPriority {
+    Low,
+    Normal,
+    High,
+}
+
+/// 通知の理由（reason）から優先度を決定
+///
+/// `[notification.priority]` で理由ごとに指定されている場合はその優先度を使用する
+pub fn priority_for(
+    notification: &Notification,
+    config: &NotificationConfig,
+) -> NotificationPriority {
+    config
+        .priority
+        .get(&notification.reason)
+        .copied()
+        .unwrap_or_else(|| default_priority_for(&notification.reason))
+}
+
+/// 理由ごとのデフォルトの優先度
+fn default_priority_for(reason: &str) -> NotificationPriority {
+    match reason {
+        "review_requested" | "mention" | "team_mention" | "assign" | "security_alert" => {
+            NotificationPriority::High
+        }
+        "subscribed" | "state_change" | "ci_activity" => NotificationPriority::Low,
+        _ => NotificationPriority::Normal,
+    }
+}
+
+/// 優先度からデスクトップ通知の表示時間を決定
+///
+/// 高優先度の通知は閉じるまで表示し、低優先度の通知は `low_priority_timeout_ms` で自動的に消す
+pub fn timeout_for(
+    priority: NotificationPriority,
+    config: &NotificationConfig,
+) -> NotificationTimeout {
+    match priority {
+        NotificationPriority::High => NotificationTimeout::Never,
+        NotificationPriority::Normal => NotificationTimeout::Default,
+        NotificationPriority::Low => {
+            NotificationTimeout::Milliseconds(config.low_priority_timeout_ms)
+        }
+    }
+}
+
+/// 通知の表示時にサウンドを鳴らすかどうか
+///
+/// `sound_min_priority` 以上の優先度の通知のみサウンドを鳴らす（未設定の場合は鳴らさない）
+pub fn should_play_sound(priority: NotificationPriority, config: &NotificationConfig) -> bool {
+    config
+        .sound_min_priority
+        .is_some_and(|min_priority| priority >= min_priority)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_notification(reason: &str) -> Notification {
+        Notification {
+            reason: reason.to_string(),
+            ..crate::test_support::notification("1", "user/repo")
+        }
+    }
+
+    #[test]
+    fn test_priority_timeout() {
+        let config = NotificationConfig {
+            low_priority_timeout_ms: 3000,
+            ..Default::default()
+        };
+
+        let high = priority_for(&create_test_notification("review_requested"), &config);
+        assert_eq!(high, NotificationPriority::High);
+        assert_eq!(timeout_for(high, &config), NotificationTimeout::Never);
+
+        let low = priority_for(&create_test_notification("subscribed"), &config);
+        assert_eq!(low, NotificationPriority::Low);
+        assert_eq!(
+            timeout_for(low, &config),
+            NotificationTimeout::Milliseconds(3000)
+        );
+
+        let normal = priority_for(&create_test_notification("comment"), &config);
+        assert_eq!(timeout_for(normal, &config), NotificationTimeout::Default);
+    }
+
+    #[test]
+    fn test_priority_overrides() {
+        let config = NotificationConfig {
+            priority: std::collections::BTreeMap::from([
+                ("subscribed".to_string(), NotificationPriority::High),
+                ("mention".to_string(), NotificationPriority::Low),
+            ]),
+            ..Default::default()
+        };
+
+        let priority = |reason: &str| priority_for(&create_test_notification(reason), &config);
+        assert_eq!(priority("subscribed"), NotificationPriority::High);
+        assert_eq!(priority("mention"), NotificationPriority::Low);
+        // 指定していない理由はデフォルトの優先度
+        assert_eq!(priority("review_requested"), NotificationPriority::High);
+        assert_eq!(priority("comment"), NotificationPriority::Normal);
+    }
+
+    #[test]
+    fn test_sound_min_priority() {
+        let default_config = NotificationConfig::default();
+        assert!(!should_play_sound(
+            NotificationPriority::High,
+            &default_config
+        ));
+
+        let config = NotificationConfig {
+            sound_min_priority: Some(NotificationPriority::High),
+            ..Default::default()
+        };
+        let low = priority_for(&create_test_notification("subscribed"), &config);
+        assert!(!should_play_sound(low, &config));
+        let normal = priority_for(&create_test_notification("comment"), &config);
+        assert!(!should_play_sound(normal, &config));
+        let high = priority_for(&create_test_notification("review_requested"), &config);
+        assert!(should_play_sound(high, &config));
+    }
+}
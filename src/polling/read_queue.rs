@@ -0,0 +1,193 @@
+use crate::GitHubClient;
+use serde::{Deserialize, Serialize};
+
+/// 既読にする操作を再試行する最大回数（最初の失敗を含む）
+pub const MAX_MARK_READ_ATTEMPTS: u32 = 5;
+
+/// GitHub で既読にできなかった通知の再試行待ちキュー（状態ファイルに保存し、再起動後も再試行する）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReadQueue {
+    entries: Vec<PendingRead>,
+}
+
+/// 再試行待ちの既読操作
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingRead {
+    /// 通知のスレッドID
+    pub thread_id: String,
+    /// 失敗した回数
+    pub attempts: u32,
+    /// 最後に失敗したときのエラー
+    pub last_error: String,
+}
+
+impl ReadQueue {
+    /// 既読にできなかった通知をキューに追加（すでにある場合は失敗として数える）
+    pub fn enqueue(&mut self, thread_id: &str, error: String) {
+        if self.contains(thread_id) {
+            if let Some(given_up) = self.record_failure(thread_id, error) {
+                tracing::error!(
+                    "Giving up marking notification {} as read after {} attempts: {}",
+                    given_up.thread_id,
+                    given_up.attempts,
+                    given_up.last_error
+                );
+            }
+            return;
+        }
+        self.entries.push(PendingRead {
+            thread_id: thread_id.to_string(),
+            attempts: 1,
+            last_error: error,
+        });
+    }
+
+    /// 再試行待ちのスレッドID
+    pub fn thread_ids(&self) -> Vec<String> {
+        self.entries.iter().map(|e| e.thread_id.clone()).collect()
+    }
+
+    /// GitHub で既読になったためキューから削除
+    pub fn record_success(&mut self, thread_id: &str) {
+        self.entries.retain(|e| e.thread_id != thread_id);
+    }
+
+    /// 再試行の失敗を記録し、上限に達した場合はキューから削除してその操作を返す
+    pub fn record_failure(&mut self, thread_id: &str, error: String) -> Option<PendingRead> {
+        let index = self.entries.iter().position(|e| e.thread_id == thread_id)?;
+        let entry = &mut self.entries[index];
+        entry.attempts += 1;
+        entry.last_error = error;
+        if entry.attempts >= MAX_MARK_READ_ATTEMPTS {
+            Some(self.entries.remove(index))
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, thread_id: &str) -> bool {
+        self.entries.iter().any(|e| e.thread_id == thread_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// 通知を既読にし、失敗した場合は次のポーリングで再試行するようにキューに追加
+pub async fn mark_as_read_or_enqueue(
+    github_client: &mut GitHubClient,
+    read_queue: &mut ReadQueue,
+    thread_id: &str,
+) {
+    if let Err(e) = github_client.mark_notification_as_read(thread_id).await {
+        enqueue_failed_read(read_queue, thread_id, &e);
+    }
+}
+
+/// 複数の通知を同時に最大 `max_concurrent` 件のリクエストで既読にし、失敗したものをキューに追加
+pub async fn mark_all_as_read_or_enqueue(
+    github_client: &mut GitHubClient,
+    read_queue: &mut ReadQueue,
+    thread_ids: &[String],
+    max_concurrent: usize,
+) {
+    let results = github_client
+        .mark_notifications_as_read(thread_ids, max_concurrent)
+        .await;
+    for (thread_id, result) in thread_ids.iter().zip(results) {
+        if let Err(e) = result {
+            enqueue_failed_read(read_queue, thread_id, &e);
+        }
+    }
+}
+
+fn enqueue_failed_read(read_queue: &mut ReadQueue, thread_id: &str, error: &crate::AuthError) {
+    tracing::warn!(
+        "Failed to mark notification {} as read, will retry on the next poll: {}",
+        thread_id,
+        error
+    );
+    read_queue.enqueue(thread_id, error.to_string());
+}
+
+/// キューにある既読操作を再試行（成功したものはキューから削除）
+pub async fn retry_pending_reads(github_client: &mut GitHubClient, read_queue: &mut ReadQueue) {
+    if read_queue.is_empty() {
+        return;
+    }
+
+    tracing::debug!(
+        count = read_queue.len(),
+        "Retrying pending mark-as-read operations"
+    );
+    for thread_id in read_queue.thread_ids() {
+        match github_client.mark_notification_as_read(&thread_id).await {
+            Ok(()) => {
+                tracing::info!("Marked notification {} as read after retrying", thread_id);
+                read_queue.record_success(&thread_id);
+            }
+            Err(e) => {
+                if let Some(given_up) = read_queue.record_failure(&thread_id, e.to_string()) {
+                    tracing::error!(
+                        "Giving up marking notification {} as read after {} attempts: {}",
+                        given_up.thread_id,
+                        given_up.attempts,
+                        given_up.last_error
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_read_is_retried_until_success() {
+        let mut queue = ReadQueue::default();
+        queue.enqueue("1", "connection reset".to_string());
+        queue.enqueue("2", "rate limited".to_string());
+        assert_eq!(queue.thread_ids(), vec!["1", "2"]);
+
+        // 再試行に失敗しても上限まではキューに残る
+        assert_eq!(queue.record_failure("1", "timeout".to_string()), None);
+        assert!(queue.contains("1"));
+
+        // 成功したらキューから削除
+        queue.record_success("1");
+        assert_eq!(queue.thread_ids(), vec!["2"]);
+
+        // キューは状態ファイルに保存して再起動後も引き継がれる
+        let json = serde_json::to_string(&queue).unwrap();
+        let restored: ReadQueue = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, queue);
+    }
+
+    #[test]
+    fn test_failed_read_is_dropped_after_max_attempts() {
+        let mut queue = ReadQueue::default();
+        queue.enqueue("1", "error".to_string());
+        for _ in 2..MAX_MARK_READ_ATTEMPTS {
+            assert_eq!(queue.record_failure("1", "error".to_string()), None);
+        }
+
+        let given_up = queue.record_failure("1", "still failing".to_string());
+        assert_eq!(
+            given_up,
+            Some(PendingRead {
+                thread_id: "1".to_string(),
+                attempts: MAX_MARK_READ_ATTEMPTS,
+                last_error: "still failing".to_string(),
+            })
+        );
+        assert!(queue.is_empty());
+    }
+}
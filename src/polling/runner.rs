@@ -1,251 +1,1928 @@
+use crate::config::{BatchSortOrder, NotificationConfig, NotificationMode};
 use crate::poller::Notifier;
+use crate::polling::backoff::backoff_interval;
+use crate::polling::filter::FilterStats;
+use crate::polling::popup_gate::PopupGate;
+use crate::polling::priority::{NotificationPriority, priority_for, timeout_for};
+use crate::polling::read_queue::{ReadQueue, mark_as_read_or_enqueue, retry_pending_reads};
+use crate::polling::schedule::{PollSchedule, PollSource, SeenNotifications};
+use crate::polling::urls::notifications_page_url;
 use crate::{Config, GitHubClient, Notification, StateManager};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::time::Duration as StdDuration;
 use tokio::sync::broadcast;
 use tokio::time::{Instant, interval};
 
+/// ダイジェストに表示する上位グループ（デフォルトはリポジトリ）の数
+const DIGEST_TOP_GROUPS: usize = 3;
+
+/// シャットダウン時にバッチの残りを送信する最大時間
+const SHUTDOWN_DRAIN_TIMEOUT: StdDuration = StdDuration::from_secs(3);
+
+/// ポーリングループの各サイクル間で保持する状態
+pub(crate) struct PollingContext {
+    /// バッチ処理用のバッファ
+    batch_buffer: VecDeque<Notification>,
+    /// 最後にバッチを処理した時刻
+    last_batch_time: Instant,
+    /// ダイジェストモード用の状態
+    digest: DigestState,
+    /// 前回のサマリー以降にフィルタで除外された通知の件数
+    filter_stats: FilterStats,
+    /// 最後に除外された通知のサマリーを出力した時刻
+    last_filtered_summary: Instant,
+    /// リポジトリごとの取得で処理済みの通知
+    seen: SeenNotifications,
+    /// レート制限によりポーリング間隔を延ばしているかどうか
+    backoff_engaged: bool,
+    /// `polling.active_hours` / `active_days` の時間帯の外でポーリングを停止しているかどうか
+    paused_outside_active_hours: bool,
+    /// GitHub で既読にできず再試行を待っている通知（状態ファイルと同期）
+    pending_reads: ReadQueue,
+    /// セカンダリレート制限により、次のポーリングまで待機する時間
+    secondary_rate_limit_wait: Option<StdDuration>,
+}
+
+impl PollingContext {
+    pub(crate) fn new() -> Self {
+        PollingContext {
+            batch_buffer: VecDeque::new(),
+            last_batch_time: Instant::now(),
+            digest: DigestState::new(),
+            filter_stats: FilterStats::default(),
+            last_filtered_summary: Instant::now(),
+            seen: SeenNotifications::default(),
+            backoff_engaged: false,
+            secondary_rate_limit_wait: None,
+            paused_outside_active_hours: false,
+            pending_reads: ReadQueue::default(),
+        }
+    }
+}
+
+/// ダイジェストモードで使用する未読通知のスナップショット
+struct DigestState {
+    /// フィルタ条件を満たす未読通知の件数（`notification.group_by` のグループごと、none の場合はリポジトリごと）
+    unread_by_group: HashMap<String, usize>,
+    /// 最後にダイジェストを送信した時刻
+    last_sent: Instant,
+}
+
+impl DigestState {
+    fn new() -> Self {
+        DigestState {
+            unread_by_group: HashMap::new(),
+            last_sent: Instant::now(),
+        }
+    }
+
+    /// 取得した通知一覧から未読件数のスナップショットを更新
+    fn update(&mut self, notifications: &[Notification], config: &Config) {
+        self.unread_by_group.clear();
+        for notification in notifications.iter().filter(|n| {
+            n.unread
+                && crate::polling::filter::matches_filters(n, config)
+                && !crate::polling::filter::is_auto_read(n, config)
+        }) {
+            let key =
+                crate::polling::grouping::group_key(notification, config.notification.group_by)
+                    .unwrap_or_else(|| notification.repository.full_name.clone());
+            *self.unread_by_group.entry(key).or_insert(0) += 1;
+        }
+    }
+
+    fn unread_count(&self) -> usize {
+        self.unread_by_group.values().sum()
+    }
+
+    /// 未読件数の多い順に上位のグループを取得
+    fn top_groups(&self, limit: usize) -> Vec<(&str, usize)> {
+        let mut groups: Vec<(&str, usize)> = self
+            .unread_by_group
+            .iter()
+            .map(|(name, count)| (name.as_str(), *count))
+            .collect();
+        groups.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        groups.truncate(limit);
+        groups
+    }
+}
+
 pub async fn run_polling_loop(
     config: &Config,
     github_client: &mut GitHubClient,
     state_manager: &mut StateManager,
     notifier: &dyn Notifier,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let mut interval = interval(StdDuration::from_secs(config.poll_interval_sec));
-    // バッチ処理用のバッファとタイマー
-    let batch_size = config.notification_batch_config.batch_size;
-    let batch_interval =
-        StdDuration::from_secs(config.notification_batch_config.batch_interval_sec);
-    let error_handling = &config.polling_error_handling_config;
-    let mut batch_buffer: VecDeque<Notification> = VecDeque::new();
-    let mut last_batch_time = Instant::now();
+    let mut context = PollingContext::new();
+    context.pending_reads = state_manager.state.pending_reads.clone();
+
+    announce_lifecycle(notifier, config, LifecycleEvent::Started);
+
+    let startup_delay = startup_delay(&config.polling);
+    if !startup_delay.is_zero() {
+        tokio::time::sleep(startup_delay).await;
+    }
+    refresh_member_organizations(config, github_client).await;
+
+    let mut schedule = PollSchedule::new(
+        config.poll_interval_sec,
+        &config.repository_overrides,
+        Instant::now(),
+    );
+    let mut interval = interval(schedule.tick_interval());
+
+    loop {
+        let now = interval.tick().await; // 次のポーリングまで待機
+        poll_due(
+            config,
+            github_client,
+            state_manager,
+            notifier,
+            &mut context,
+            &mut schedule,
+            now,
+        )
+        .await;
+    }
+}
+
+/// シャットダウンシグナル付きでポーリングを実行する非同期ループ
+pub async fn run_polling_loop_with_shutdown(
+    config: &Config,
+    github_client: &mut GitHubClient,
+    state_manager: &mut StateManager,
+    notifier: &dyn Notifier,
+    shutdown_rx: &mut broadcast::Receiver<()>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // SIGHUP で再読み込みした設定に置き換えるため、設定を所有する
+    let mut config = config.clone();
+    let mut context = PollingContext::new();
+    context.pending_reads = state_manager.state.pending_reads.clone();
+
+    announce_lifecycle(notifier, &config, LifecycleEvent::Started);
+
+    // 最初のポーリングの前に待機（待機中もシャットダウンシグナルに応答する）
+    let startup_delay = startup_delay(&config.polling);
+    if !startup_delay.is_zero() {
+        tracing::info!("Waiting {:?} before the first poll", startup_delay);
+        tokio::select! {
+            _ = tokio::time::sleep(startup_delay) => {}
+            _ = shutdown_rx.recv() => {
+                tracing::info!("Shutdown signal received during startup delay, exiting...");
+                announce_lifecycle(notifier, &config, LifecycleEvent::Stopped);
+                return Ok(());
+            }
+        }
+    }
+
+    refresh_member_organizations(&config, github_client).await;
+    // SIGHUP で設定ファイルを再読み込みし、所属する組織の一覧を取得し直す
+    let mut hangup = crate::shutdown::HangupSignal::new(true);
+
+    let mut schedule = PollSchedule::new(
+        config.poll_interval_sec,
+        &config.repository_overrides,
+        Instant::now(),
+    );
+    let mut interval = interval(schedule.tick_interval());
+
+    loop {
+        // シャットダウンシグナルを待機しつつ、ポーリング間隔を待機
+        tokio::select! {
+            now = interval.tick() => {
+                poll_due(
+                    &config,
+                    github_client,
+                    state_manager,
+                    notifier,
+                    &mut context,
+                    &mut schedule,
+                    now,
+                )
+                .await;
+            }
+            _ = hangup.recv() => {
+                tracing::info!("SIGHUP received, reloading config");
+                if let Some(reloaded) = reload_config(&config, state_manager.is_read_only()) {
+                    if polling_schedule_changed(&config, &reloaded) {
+                        schedule = PollSchedule::new(
+                            reloaded.poll_interval_sec,
+                            &reloaded.repository_overrides,
+                            Instant::now(),
+                        );
+                        interval = tokio::time::interval(schedule.tick_interval());
+                    }
+                    config = reloaded;
+                }
+                refresh_member_organizations(&config, github_client).await;
+            }
+            _ = shutdown_rx.recv() => {
+                tracing::info!("Shutdown signal received, saving state and exiting...");
+                // バッファに残っている通知を送信してから終了
+                drain_batch_on_shutdown(notifier, github_client, state_manager, &config, &mut context)
+                    .await;
+                // 終了前に状態を保存
+                state_manager.state.pending_reads = context.pending_reads.clone();
+                if let Err(e) = state_manager.save() {
+                    tracing::error!("Failed to save state on shutdown: {}", e);
+                }
+                tracing::info!("State saved, exiting polling loop");
+                announce_lifecycle(notifier, &config, LifecycleEvent::Stopped);
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// SIGHUP で設定ファイルを再読み込み（読み込み・検証に失敗した場合は `None` で、現在の設定のまま）
+///
+/// `read_only`（`--dry-run`）の場合は起動時と同じく書き込みを行わない設定に変換する
+fn reload_config(current: &Config, read_only: bool) -> Option<Config> {
+    let reloaded = match crate::config::load_config() {
+        Ok(config) => config,
+        Err(e) => {
+            tracing::error!("Failed to reload config, keeping the current config: {}", e);
+            return None;
+        }
+    };
+    let reloaded = if read_only {
+        crate::app::dry_run_config(reloaded)
+    } else {
+        reloaded
+    };
+
+    let ignored = restart_required_changes(current, &reloaded);
+    if !ignored.is_empty() {
+        tracing::warn!(
+            "Config reloaded, but changes to {} require a restart",
+            ignored.join(", ")
+        );
+    }
+    tracing::info!("Config reloaded");
+    Some(reloaded)
+}
+
+/// 再読み込みでは反映されず、再起動が必要な設定のうち変更されたもの
+fn restart_required_changes(current: &Config, reloaded: &Config) -> Vec<&'static str> {
+    [
+        ("pat", changed(&current.pat, &reloaded.pat)),
+        ("[github]", changed(&current.github, &reloaded.github)),
+        ("[auth]", changed(&current.auth, &reloaded.auth)),
+        (
+            "log_level",
+            changed(&current.log_level, &reloaded.log_level),
+        ),
+        (
+            "log_file_path",
+            changed(&current.log_file_path, &reloaded.log_file_path),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, changed)| changed.then_some(name))
+    .collect()
+}
+
+/// ポーリング間隔（`poll_interval_sec`、`repository_overrides`）が変更されたかどうか
+fn polling_schedule_changed(current: &Config, reloaded: &Config) -> bool {
+    current.poll_interval_sec != reloaded.poll_interval_sec
+        || changed(
+            &current.repository_overrides,
+            &reloaded.repository_overrides,
+        )
+}
+
+/// 設定の値が変更されたかどうか（設定の型は `PartialEq` を実装していないため JSON で比較）
+fn changed<T: serde::Serialize>(current: &T, reloaded: &T) -> bool {
+    serde_json::to_value(current).ok() != serde_json::to_value(reloaded).ok()
+}
+
+/// 自分が所属する組織の一覧を GitHub から取得（`member_organizations_only`）
+///
+/// 取得に失敗した場合は前回の一覧のまま（初回の場合は組織で除外しない）
+async fn refresh_member_organizations(config: &Config, github_client: &mut GitHubClient) {
+    let filters = &config.notification_filters;
+    if !filters.member_organizations_only || !filters.include_organizations.is_empty() {
+        return;
+    }
+
+    match github_client.get_user_organizations().await {
+        Ok(organizations) => {
+            tracing::info!(
+                count = organizations.len(),
+                "Loaded organization memberships for member_organizations_only"
+            );
+            crate::polling::filters::organization_filter::set_member_organizations(organizations);
+        }
+        Err(e) => tracing::warn!("Failed to get organization memberships: {}", e),
+    }
+}
+
+/// 最初のポーリングまでの待機時間（`startup_delay_sec` + 0〜`startup_jitter_sec` のランダムな揺らぎ）
+fn startup_delay(polling: &crate::config::PollingConfig) -> StdDuration {
+    let jitter_ms = if polling.startup_jitter_sec > 0 {
+        rand::Rng::gen_range(
+            &mut rand::thread_rng(),
+            0..=polling.startup_jitter_sec.saturating_mul(1000),
+        )
+    } else {
+        0
+    };
+    StdDuration::from_secs(polling.startup_delay_sec) + StdDuration::from_millis(jitter_ms)
+}
+
+/// デーモンのライフサイクルイベント
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LifecycleEvent {
+    Started,
+    Stopped,
+}
+
+/// `notification.announce_lifecycle` が有効な場合に起動・終了を通知
+fn announce_lifecycle(notifier: &dyn Notifier, config: &Config, event: LifecycleEvent) {
+    if !config.notification.announce_lifecycle {
+        return;
+    }
+
+    let (title, body) = match event {
+        LifecycleEvent::Started => {
+            let repositories = &config.notification_filters.include_repositories;
+            let body = if repositories.is_empty() {
+                "Monitoring notifications from all repositories".to_string()
+            } else {
+                format!("Monitoring {} repositories", repositories.len())
+            };
+            ("gh-notifier started", body)
+        }
+        LifecycleEvent::Stopped => ("gh-notifier stopped", "Shut down cleanly".to_string()),
+    };
+
+    if let Err(e) = notifier.send_notification(
+        title,
+        &body,
+        &notifications_page_url(&config.github.web_base_url()),
+    ) {
+        tracing::warn!("Failed to send lifecycle notification: {}", e);
+    }
+}
+
+/// `now` の時点で取得時刻になった取得元（全体の通知一覧・個別のリポジトリ）をポーリング
+async fn poll_due(
+    config: &Config,
+    github_client: &mut GitHubClient,
+    state_manager: &mut StateManager,
+    notifier: &dyn Notifier,
+    context: &mut PollingContext,
+    schedule: &mut PollSchedule,
+    now: Instant,
+) {
+    // 時間帯の外では取得時刻を進めず、再開時にすべての取得元をまとめて取得する
+    if !within_active_hours(config, context, chrono::Local::now().naive_local()) {
+        return;
+    }
+
+    let sources = schedule.due(now);
+    if sources.is_empty() {
+        return;
+    }
+
+    retry_pending_reads(github_client, &mut context.pending_reads).await;
+
+    for source in sources {
+        // セカンダリレート制限中は残りの取得元も取得しない
+        if context.secondary_rate_limit_wait.is_some() {
+            break;
+        }
+        match source {
+            PollSource::Inbox => {
+                poll_once(config, github_client, state_manager, notifier, context).await;
+            }
+            PollSource::Repository(full_name) => {
+                poll_repository_once(
+                    &full_name,
+                    config,
+                    github_client,
+                    state_manager,
+                    notifier,
+                    context,
+                    schedule,
+                )
+                .await;
+            }
+        }
+    }
+
+    if config.polling.adaptive_backoff {
+        apply_adaptive_backoff(config, github_client, context, schedule, now);
+    }
+    if let Some(wait) = context.secondary_rate_limit_wait.take() {
+        tracing::warn!(
+            "Secondary rate limit hit, pausing polling for {}s",
+            wait.as_secs()
+        );
+        schedule.defer_until(now + wait);
+    }
+
+    sync_pending_reads(state_manager, context);
+}
+
+/// 既読操作の再試行キューに変化があれば状態ファイルに保存
+fn sync_pending_reads(state_manager: &mut StateManager, context: &PollingContext) {
+    if state_manager.state.pending_reads == context.pending_reads {
+        return;
+    }
+    state_manager.state.pending_reads = context.pending_reads.clone();
+    if let Err(e) = state_manager.save() {
+        tracing::error!("Failed to save pending mark-as-read operations: {}", e);
+    }
+}
+
+/// `now`（ローカル時刻）がポーリングを行う時間帯かどうかを判定し、停止・再開したときにログを出力
+fn within_active_hours(
+    config: &Config,
+    context: &mut PollingContext,
+    now: chrono::NaiveDateTime,
+) -> bool {
+    let active = match crate::polling::active_hours::ActiveSchedule::from_config(&config.polling) {
+        Ok(Some(schedule)) => schedule.is_active(now),
+        // 設定の誤りは load_config で検出されるため、ここでは常にポーリングする
+        Ok(None) | Err(_) => true,
+    };
+
+    if active && context.paused_outside_active_hours {
+        tracing::info!("Active hours resumed, catching up on notifications");
+    } else if !active && !context.paused_outside_active_hours {
+        tracing::info!("Outside active hours, pausing polling until they resume");
+    }
+    context.paused_outside_active_hours = !active;
+    active
+}
+
+/// 現在デスクトップ通知の表示のみを抑制するかどうか（おやすみモード・静かな時間帯）
+///
+/// Webhook・Slack への送信や既読にする処理は抑制しない
+fn popups_suppressed(config: &Config) -> bool {
+    if crate::dnd::should_suppress(config.notification.dnd) {
+        tracing::info!("Do Not Disturb is active, suppressing desktop notifications");
+        return true;
+    }
+    if in_quiet_hours(config, chrono::Local::now().naive_local()) {
+        tracing::info!("Quiet hours, suppressing desktop notifications");
+        return true;
+    }
+    false
+}
+
+/// `now`（ローカル時刻）がデスクトップ通知を抑制する時間帯（`notification.quiet_hours`）かどうか
+fn in_quiet_hours(config: &Config, now: chrono::NaiveDateTime) -> bool {
+    match crate::polling::active_hours::ActiveSchedule::from_quiet_hours(&config.notification) {
+        Ok(Some(schedule)) => schedule.is_active(now),
+        // 設定の誤りは load_config で検出されるため、ここでは抑制しない
+        Ok(None) | Err(_) => false,
+    }
+}
+
+/// レート制限の残りが少ない場合に次のポーリングを遅らせる（`polling.adaptive_backoff`）
+fn apply_adaptive_backoff(
+    config: &Config,
+    github_client: &GitHubClient,
+    context: &mut PollingContext,
+    schedule: &mut PollSchedule,
+    now: Instant,
+) {
+    let Some(rate_limit) = github_client.rate_limit() else {
+        return;
+    };
+    let now_unix = chrono::Utc::now().timestamp().max(0) as u64;
+    let stretched = backoff_interval(
+        &rate_limit,
+        StdDuration::from_secs(config.poll_interval_sec),
+        config.polling.rate_limit_threshold,
+        now_unix,
+    );
+
+    match stretched {
+        Some(stretched) => {
+            if !context.backoff_engaged {
+                tracing::warn!(
+                    "Rate limit is running low ({}/{} remaining, resets at {}), backing off polling to every {}s",
+                    rate_limit.remaining,
+                    rate_limit.limit,
+                    rate_limit.reset,
+                    stretched.as_secs()
+                );
+                context.backoff_engaged = true;
+            } else {
+                tracing::debug!(
+                    "Rate limit backoff: {} remaining, next poll in {}s",
+                    rate_limit.remaining,
+                    stretched.as_secs()
+                );
+            }
+            schedule.defer_until(now + stretched);
+        }
+        None => {
+            if context.backoff_engaged {
+                tracing::info!(
+                    "Rate limit recovered ({}/{} remaining), polling at the normal interval",
+                    rate_limit.remaining,
+                    rate_limit.limit
+                );
+                context.backoff_engaged = false;
+            }
+        }
+    }
+}
+
+/// セカンダリレート制限のエラーの場合は `Retry-After` の時間を記録（次のポーリングをその分遅らせる）
+fn record_secondary_rate_limit(error: &crate::AuthError, context: &mut PollingContext) {
+    if let crate::AuthError::SecondaryRateLimit { retry_after, .. } = error {
+        context.secondary_rate_limit_wait = Some(*retry_after);
+    }
+}
+
+/// `repository_overrides` のリポジトリの通知を個別に取得して処理
+///
+/// 全体の最終確認日時は更新せず、処理した通知を記録して全体の通知一覧での重複を防ぐ
+async fn poll_repository_once(
+    full_name: &str,
+    config: &Config,
+    github_client: &mut GitHubClient,
+    state_manager: &mut StateManager,
+    notifier: &dyn Notifier,
+    context: &mut PollingContext,
+    schedule: &mut PollSchedule,
+) {
+    // 全体の通知一覧ですでに処理した通知を再度処理しないように、新しい方の日時を使用
+    let since = match (
+        schedule.repository_last_checked_at(full_name),
+        state_manager.get_last_checked_at(),
+    ) {
+        (Some(repository), Some(global)) => Some(repository.max(global).to_string()),
+        (repository, global) => repository.or(global).map(str::to_string),
+    };
+
+    let notifications = match github_client
+        .get_repository_notifications(full_name, since.as_deref())
+        .await
+    {
+        Ok(Some(notifications)) => notifications,
+        Ok(None) => {
+            tracing::debug!(repository = %full_name, "No new repository notifications (304 Not Modified)");
+            return;
+        }
+        Err(e) => {
+            tracing::error!("Error fetching notifications for {}: {}", full_name, e);
+            record_secondary_rate_limit(&e, context);
+            return;
+        }
+    };
+
+    let new_notifications = crate::polling::filter::filter_notifications_since_with_stats(
+        &notifications,
+        since.as_deref(),
+        config,
+        &mut context.filter_stats,
+    );
+    if let Some(latest) = new_notifications.iter().max_by_key(|n| &n.updated_at) {
+        schedule.set_repository_last_checked_at(full_name, latest.updated_at.clone());
+    }
+
+    let new_notifications: Vec<&Notification> = new_notifications
+        .into_iter()
+        .filter(|n| !context.seen.contains(n))
+        .collect();
+    if new_notifications.is_empty() {
+        return;
+    }
+
+    for notification in &new_notifications {
+        context.seen.mark(notification);
+    }
+    process_new_notifications(
+        &new_notifications,
+        config,
+        github_client,
+        state_manager,
+        notifier,
+        context,
+    )
+    .await;
+
+    if let Err(e) = state_manager.save() {
+        tracing::error!("Failed to save state: {}", e);
+    }
+}
+
+/// 新しい通知を送信し、送信した通知の累計件数を記録
+async fn process_new_notifications(
+    new_notifications: &[&Notification],
+    config: &Config,
+    github_client: &mut GitHubClient,
+    state_manager: &mut StateManager,
+    notifier: &dyn Notifier,
+    context: &mut PollingContext,
+) {
+    let labeled = apply_label_filters(new_notifications, config, github_client, context).await;
+    let new_notifications = labeled.as_slice();
+    if new_notifications.is_empty() {
+        return;
+    }
+
+    let delivered = dispatch_notifications(
+        new_notifications,
+        notifier,
+        github_client,
+        state_manager,
+        config,
+        context,
+    )
+    .await;
+
+    // 送信した通知のみを累計件数に加える
+    state_manager.record_notifications(&delivered, config.stats.reset_monthly, chrono::Utc::now());
+}
+
+/// ラベルのフィルタ（`include_labels` / `exclude_labels`）を適用
+///
+/// ラベルはサブジェクトの API から取得し、同じサブジェクトの通知が複数ある場合は1回だけ取得する。
+/// 取得に失敗した通知は除外せずに残し、判定できなかった件数として `filter_stats` に記録する
+async fn apply_label_filters<'a>(
+    notifications: &[&'a Notification],
+    config: &Config,
+    github_client: &mut GitHubClient,
+    context: &mut PollingContext,
+) -> Vec<&'a Notification> {
+    use crate::polling::filters::label_filter::{
+        LabelDecision, decide_by_labels, has_label_filters,
+    };
+
+    if !has_label_filters(config) {
+        return notifications.to_vec();
+    }
+
+    // 同じ PR・Issue のラベルは1回だけ、同時に最大 polling.max_concurrent 件のリクエストで取得
+    let mut subject_urls: Vec<String> = Vec::new();
+    for notification in notifications {
+        if let Some(url) = &notification.subject.url
+            && !subject_urls.contains(url)
+        {
+            subject_urls.push(url.clone());
+        }
+    }
+    let results = github_client
+        .get_subjects_labels(&subject_urls, config.polling.max_concurrent)
+        .await;
+    let labels_by_url: HashMap<String, Option<Vec<String>>> = subject_urls
+        .into_iter()
+        .zip(results)
+        .map(|(url, result)| {
+            let labels = match result {
+                Ok(labels) => Some(labels),
+                Err(e) => {
+                    tracing::warn!("Failed to get labels for {}: {}", url, e);
+                    None
+                }
+            };
+            (url, labels)
+        })
+        .collect();
+
+    let mut kept = Vec::with_capacity(notifications.len());
+    for &notification in notifications {
+        let labels = match &notification.subject.url {
+            Some(url) => labels_by_url[url].clone(),
+            // ラベルを持たないサブジェクト（コミット、リリースなど）
+            None => Some(Vec::new()),
+        };
+
+        match decide_by_labels(labels.as_deref(), config) {
+            LabelDecision::Keep => kept.push(notification),
+            LabelDecision::Reject => context.filter_stats.record("label".to_string()),
+            LabelDecision::KeepUnverified => {
+                tracing::warn!(
+                    "Delivering notification {} without checking its labels",
+                    notification.id
+                );
+                context.filter_stats.record_unverified("label");
+                kept.push(notification);
+            }
+        }
+    }
+    kept
+}
+
+/// 1回分のポーリング処理（通知の取得、フィルタリング、通知の送信、状態の保存）
+async fn poll_once(
+    config: &Config,
+    github_client: &mut GitHubClient,
+    state_manager: &mut StateManager,
+    notifier: &dyn Notifier,
+    context: &mut PollingContext,
+) {
+    // StateManager から最終確認日時と前回の ETag を取得
+    let if_modified_since = state_manager.get_last_checked_at();
+    // ETag はクエリを含む URL ごとに記録する（クエリを変更した場合に古い ETag で 304 にならないように）
+    let notifications_url = config.github.notifications_url("/notifications");
+    let etag = state_manager.get_etag(&notifications_url);
+
+    // GitHub API から通知を取得
+    match github_client
+        .get_notifications(if_modified_since, etag)
+        .await
+    {
+        Ok(Some(notifications)) => {
+            // 次回のポーリングで If-None-Match に使用する ETag を記録
+            let etag_changed = match github_client.last_etag() {
+                Some(etag) if state_manager.get_etag(&notifications_url) != Some(etag) => {
+                    state_manager.update_etag(notifications_url.clone(), etag.to_string());
+                    true
+                }
+                _ => false,
+            };
+            // `stats` で表示する未読件数を更新
+            let unread_changed =
+                state_manager.update_unread_counts(&notifications, chrono::Utc::now());
+
+            if config.notification.mode == NotificationMode::Digest {
+                context.digest.update(&notifications, config);
+            }
+
+            // 最終確認日時以降の新しい通知のみを処理
+            let new_notifications = crate::polling::filter::filter_new_notifications_with_stats(
+                &notifications,
+                state_manager,
+                config,
+                &mut context.filter_stats,
+            );
+
+            if !new_notifications.is_empty() {
+                // 最新の通知の updated_at を最終確認日時として更新
+                if let Some(latest) = new_notifications.iter().max_by_key(|n| &n.updated_at) {
+                    state_manager.update_last_checked_at(latest.updated_at.clone());
+                }
+
+                // リポジトリごとの取得ですでに処理した通知を除外
+                let new_notifications: Vec<&Notification> = new_notifications
+                    .into_iter()
+                    .filter(|n| !context.seen.contains(n))
+                    .collect();
+                if let Some(last_checked) = state_manager.get_last_checked_at() {
+                    context.seen.prune_through(last_checked);
+                }
+
+                if !new_notifications.is_empty() {
+                    process_new_notifications(
+                        &new_notifications,
+                        config,
+                        github_client,
+                        state_manager,
+                        notifier,
+                        context,
+                    )
+                    .await;
+                }
+
+                // 状態を保存
+                if let Err(e) = state_manager.save() {
+                    tracing::error!("Failed to save state: {}", e);
+                }
+            } else if (etag_changed || unread_changed)
+                && let Err(e) = state_manager.save()
+            {
+                tracing::error!("Failed to save state: {}", e);
+            }
+        }
+        Ok(None) => {
+            // 304 Not Modified（キャッシュした ETag はそのまま使用する）
+            tracing::debug!("No new notifications (304 Not Modified)");
+        }
+        Err(e) => {
+            tracing::error!("Error fetching notifications: {}", e);
+            record_secondary_rate_limit(&e, context);
+        }
+    }
+
+    if config.notification.mode == NotificationMode::Digest {
+        send_digest_if_due(notifier, config, context);
+    }
+
+    if config.filtered_summary.enabled {
+        emit_filtered_summary_if_due(notifier, config, context);
+    }
+}
+
+/// 除外された通知のサマリーの間隔が経過していればログ（と任意でデスクトップ通知）に出力
+fn emit_filtered_summary_if_due(
+    notifier: &dyn Notifier,
+    config: &Config,
+    context: &mut PollingContext,
+) {
+    let interval = StdDuration::from_secs(config.filtered_summary.interval_sec);
+    if context.last_filtered_summary.elapsed() < interval {
+        return;
+    }
+    context.last_filtered_summary = Instant::now();
+
+    let stats = &context.filter_stats;
+    if stats.total() == 0 && stats.unverified_total() == 0 {
+        return;
+    }
+
+    let breakdown = stats
+        .breakdown()
+        .iter()
+        .map(|(reason, count)| format!("{} {}", count, reason))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut summary = format!(
+        "Filtered {} notifications since the last summary: {}",
+        stats.total(),
+        breakdown
+    );
+    if stats.unverified_total() > 0 {
+        let unverified = stats
+            .unverified_breakdown()
+            .iter()
+            .map(|(filter, count)| format!("{} {}", count, filter))
+            .collect::<Vec<_>>()
+            .join(", ");
+        summary.push_str(&format!(
+            " (delivered {} without checking: {})",
+            stats.unverified_total(),
+            unverified
+        ));
+    }
+    tracing::info!("{}", summary);
+
+    if config.filtered_summary.desktop_notification {
+        let timeout = timeout_for(NotificationPriority::Low, &config.notification);
+        if let Err(e) = notifier.send_notification_with_timeout(
+            "GitHub Notifier - filtered notifications",
+            &summary,
+            &notifications_page_url(&config.github.web_base_url()),
+            timeout,
+        ) {
+            tracing::warn!("Failed to send filtered summary notification: {}", e);
+        }
+    }
+
+    context.filter_stats.clear();
+}
+
+/// 新しい通知を Webhook・Slack に送信し、設定された送信モードに従ってデスクトップ通知を処理
+///
+/// 送信した（バッチ処理が有効な場合はバッファに追加した）通知を返す。
+/// 既読にした通知や、Off・Digest モード、スレッドのクールダウンで表示しなかった通知は含まない。
+/// おやすみモード・静かな時間帯はデスクトップ通知の表示のみを抑制するため、表示しなかった通知も含む
+async fn dispatch_notifications<'a>(
+    new_notifications: &[&'a Notification],
+    notifier: &dyn Notifier,
+    github_client: &mut GitHubClient,
+    state_manager: &mut StateManager,
+    config: &Config,
+    context: &mut PollingContext,
+) -> Vec<&'a Notification> {
+    // Webhook・Slack には送信モードやおやすみモードに関係なく送信する
+    crate::polling::handler::post_notifications(new_notifications, config).await;
+
+    // auto_read_reasons に一致する通知は表示せずに既読にする
+    let (auto_read, new_notifications): (Vec<&Notification>, Vec<&Notification>) =
+        new_notifications
+            .iter()
+            .copied()
+            .partition(|n| crate::polling::filter::is_auto_read(n, config));
+    for notification in auto_read {
+        tracing::debug!(
+            id = %notification.id,
+            reason = %notification.reason,
+            "Marking notification as read without showing it (auto_read_reasons)"
+        );
+        mark_as_read_or_enqueue(github_client, &mut context.pending_reads, &notification.id).await;
+    }
+    let new_notifications = new_notifications.as_slice();
+    let mut delivered = Vec::new();
+
+    match config.notification.mode {
+        NotificationMode::Off => {
+            tracing::debug!(
+                count = new_notifications.len(),
+                "Desktop notifications are disabled, skipping dispatch"
+            );
+        }
+        NotificationMode::Digest => {
+            tracing::debug!(
+                count = new_notifications.len(),
+                "Digest mode enabled, skipping individual notifications"
+            );
+        }
+        NotificationMode::Individual => {
+            let batch_size = config.notification_batch_config.batch_size;
+            let batch_interval =
+                StdDuration::from_secs(config.notification_batch_config.batch_interval_sec);
+
+            // バッチ処理が有効な場合はバッファに追加
+            if batch_size > 0 {
+                for notification in new_notifications {
+                    context.batch_buffer.push_back((*notification).clone());
+                }
+                delivered.extend_from_slice(new_notifications);
+
+                // バッチサイズに達したか、時間経過時に処理
+                if context.batch_buffer.len() >= batch_size
+                    || context.last_batch_time.elapsed() >= batch_interval
+                {
+                    if let Err(e) = process_batch(
+                        &context.batch_buffer,
+                        notifier,
+                        github_client,
+                        state_manager,
+                        config,
+                        &config.polling_error_handling_config,
+                        &mut context.pending_reads,
+                    )
+                    .await
+                    {
+                        tracing::error!("Failed to process batch: {}", e);
+                    }
+                    context.batch_buffer.clear();
+                    context.last_batch_time = Instant::now();
+                }
+            } else {
+                // バッチ処理が無効な場合は1つずつ表示し、Webhook などへの送信と既読にする処理は並行して行う
+                let mut gate = PopupGate::new(
+                    state_manager,
+                    config,
+                    popups_suppressed(config),
+                    chrono::Utc::now(),
+                );
+                delivered = crate::polling::handler::handle_notifications(
+                    new_notifications,
+                    notifier,
+                    github_client,
+                    config,
+                    &mut context.pending_reads,
+                    &mut gate,
+                )
+                .await;
+            }
+        }
+    }
+    delivered
+}
+
+/// シャットダウン時にバッチバッファに残っている通知を送信（`SHUTDOWN_DRAIN_TIMEOUT` で打ち切り）
+async fn drain_batch_on_shutdown(
+    notifier: &dyn Notifier,
+    github_client: &mut GitHubClient,
+    state_manager: &mut StateManager,
+    config: &Config,
+    context: &mut PollingContext,
+) {
+    if context.batch_buffer.is_empty() {
+        return;
+    }
+
+    tracing::info!(
+        count = context.batch_buffer.len(),
+        "Flushing pending notification batch before shutdown"
+    );
+
+    let drain = process_batch(
+        &context.batch_buffer,
+        notifier,
+        github_client,
+        state_manager,
+        config,
+        &config.polling_error_handling_config,
+        &mut context.pending_reads,
+    );
+    match tokio::time::timeout(SHUTDOWN_DRAIN_TIMEOUT, drain).await {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => tracing::error!("Failed to flush pending batch on shutdown: {}", e),
+        Err(_) => {
+            tracing::warn!("Flushing pending batch timed out, remaining notifications dropped")
+        }
+    }
+    context.batch_buffer.clear();
+}
+
+/// ダイジェストの送信間隔が経過していれば未読件数のサマリーを送信
+fn send_digest_if_due(notifier: &dyn Notifier, config: &Config, context: &mut PollingContext) {
+    let digest_interval = StdDuration::from_secs(config.notification.digest_interval_sec);
+    if context.digest.last_sent.elapsed() < digest_interval {
+        return;
+    }
+
+    let unread_count = context.digest.unread_count();
+    if unread_count == 0 {
+        return;
+    }
+
+    // おやすみモード中は送信を見送り、解除後の次のサイクルで送信する
+    if crate::dnd::should_suppress(config.notification.dnd) {
+        tracing::debug!("Do Not Disturb is active, deferring digest");
+        return;
+    }
+
+    let title = format!("GitHub Notifier - {} unread notifications", unread_count);
+    let top_groups = context
+        .digest
+        .top_groups(DIGEST_TOP_GROUPS)
+        .iter()
+        .map(|(name, count)| format!("{} ({})", name, count))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let body = format!(
+        "Top {}:\n{}",
+        crate::polling::grouping::group_label(config.notification.group_by),
+        top_groups
+    );
+
+    if let Err(e) = notifier.send_notification(
+        &title,
+        &body,
+        &notifications_page_url(&config.github.web_base_url()),
+    ) {
+        tracing::error!("Failed to send digest notification: {}", e);
+    }
+    context.digest.last_sent = Instant::now();
+}
+
+/// バッチ内の通知を送信する順序に並べる
+///
+/// 後に送信した通知ほど通知の一覧の上に表示されるため、上に表示したい通知を最後にする（並べ替えは安定）
+fn dispatch_order<'a>(
+    batch: &'a VecDeque<Notification>,
+    sort_order: BatchSortOrder,
+    notification_config: &NotificationConfig,
+) -> Vec<&'a Notification> {
+    let mut ordered: Vec<&Notification> = batch.iter().collect();
+    match sort_order {
+        BatchSortOrder::PriorityDesc => ordered.sort_by(|a, b| {
+            priority_for(a, notification_config)
+                .cmp(&priority_for(b, notification_config))
+                .then_with(|| a.updated_at.cmp(&b.updated_at))
+        }),
+        BatchSortOrder::TimeDesc => ordered.sort_by(|a, b| a.updated_at.cmp(&b.updated_at)),
+        BatchSortOrder::Arrival => {}
+    }
+    ordered
+}
+
+/// バッチ処理を実行
+async fn process_batch(
+    batch: &VecDeque<Notification>,
+    notifier: &dyn Notifier,
+    github_client: &mut GitHubClient,
+    state_manager: &mut StateManager,
+    config: &Config,
+    _error_handling: &crate::config::PollingErrorHandlingConfig,
+    read_queue: &mut ReadQueue,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let ordered = dispatch_order(
+        batch,
+        config.notification_batch_config.sort_order,
+        &config.notification,
+    );
+    let groups = crate::polling::grouping::group_notifications(
+        ordered,
+        crate::polling::grouping::batch_group_by(config),
+    );
+    let mut gate = PopupGate::new(
+        state_manager,
+        config,
+        popups_suppressed(config),
+        chrono::Utc::now(),
+    );
+    for (key, notifications) in groups {
+        let result = if let [notification] = notifications.as_slice() {
+            // 通知を Notifier に渡す
+            crate::polling::handler::handle_notification(
+                notification,
+                notifier,
+                github_client,
+                config,
+                read_queue,
+                &mut gate,
+            )
+            .await
+        } else {
+            crate::polling::handler::handle_notification_group(
+                &key,
+                &notifications,
+                notifier,
+                github_client,
+                config,
+                read_queue,
+                &mut gate,
+            )
+            .await
+        };
+        if let Err(e) = result {
+            tracing::error!("Failed to handle notification: {}", e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{DndMode, NotificationFilter};
+    use crate::{AuthManager, Config, GitHubClient, StateManager};
+    use std::sync::Mutex;
+    use tokio::sync::broadcast;
+
+    struct MockNotifier;
+
+    /// 送信された通知のタイトルを記録する Notifier
+    struct RecordingNotifier {
+        titles: Mutex<Vec<String>>,
+    }
+
+    impl RecordingNotifier {
+        fn new() -> Self {
+            RecordingNotifier {
+                titles: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn titles(&self) -> Vec<String> {
+            self.titles.lock().unwrap().clone()
+        }
+    }
+
+    impl crate::poller::Notifier for RecordingNotifier {
+        fn send_notification(
+            &self,
+            title: &str,
+            _body: &str,
+            _url: &str,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.titles.lock().unwrap().push(title.to_string());
+            Ok(())
+        }
+    }
+
+    /// 常に送信に失敗する Notifier
+    struct FailingNotifier;
+
+    impl crate::poller::Notifier for FailingNotifier {
+        fn send_notification(
+            &self,
+            _title: &str,
+            _body: &str,
+            _url: &str,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Err("notification daemon is not running".into())
+        }
+    }
+
+    fn create_test_notification(id: &str, full_name: &str) -> Notification {
+        let mut notification = crate::test_support::notification(id, full_name);
+        notification.subject.url = Some(format!("https://example.com/{}", id));
+        notification
+    }
+
+    /// 一時ファイルに状態を保存する StateManager（一時ファイルは StateManager と同じ間だけ保持する）
+    fn create_test_state_manager() -> (tempfile::NamedTempFile, StateManager) {
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let state_manager = StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        (temp_file, state_manager)
+    }
+
+    fn create_test_config(mode: NotificationMode) -> Config {
+        let mut config = Config {
+            notification_filters: NotificationFilter::default(),
+            ..Default::default()
+        };
+        config.notification.mode = mode;
+        // Keep the tests independent of the desktop's Do Not Disturb state
+        config.notification.dnd = DndMode::Off;
+        config
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_individual_mode() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let config = create_test_config(NotificationMode::Individual);
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let notifications = [
+            create_test_notification("1", "user/repo1"),
+            create_test_notification("2", "user/repo2"),
+        ];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
+
+        assert_eq!(notifier.titles().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_off_mode() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let config = create_test_config(NotificationMode::Off);
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let notifications = [create_test_notification("1", "user/repo1")];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
+        send_digest_if_due(&notifier, &config, &mut context);
+
+        assert!(notifier.titles().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_digest_mode() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let mut config = create_test_config(NotificationMode::Digest);
+        config.notification.digest_interval_sec = 0;
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let notifications = [
+            create_test_notification("1", "user/repo1"),
+            create_test_notification("2", "user/repo1"),
+            create_test_notification("3", "user/repo2"),
+        ];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+
+        // 個別の通知は送信されない
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
+        assert!(notifier.titles().is_empty());
+
+        // サマリーが送信される
+        context.digest.update(&notifications, &config);
+        assert_eq!(
+            context.digest.top_groups(DIGEST_TOP_GROUPS),
+            vec![("user/repo1", 2), ("user/repo2", 1)]
+        );
+        send_digest_if_due(&notifier, &config, &mut context);
+
+        let titles = notifier.titles();
+        assert_eq!(titles.len(), 1);
+        assert!(titles[0].contains("3 unread notifications"));
+    }
+
+    #[test]
+    fn test_digest_not_sent_before_interval() {
+        let config = create_test_config(NotificationMode::Digest);
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let notifications = [create_test_notification("1", "user/repo1")];
+        context.digest.update(&notifications, &config);
+        send_digest_if_due(&notifier, &config, &mut context);
+
+        // デフォルトの間隔（1時間）が経過していないため送信されない
+        assert!(notifier.titles().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_thread_cooldown() {
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.notification.thread_cooldown_sec = 300;
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let mut state_manager =
+            StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let first_update = create_test_notification("1", "user/repo1");
+        let mut second_update = create_test_notification("1", "user/repo1");
+        second_update.updated_at = "2023-01-02T00:01:00Z".to_string();
+
+        // A failed popup does not start the cooldown
+        dispatch_notifications(
+            &[&first_update],
+            &FailingNotifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
+        assert!(state_manager.get_thread_dispatched_at("1").is_none());
+
+        // Two updates of the same thread within the cooldown yield one popup
+        for update in [&first_update, &second_update] {
+            dispatch_notifications(
+                &[update],
+                &notifier,
+                &mut github_client,
+                &mut state_manager,
+                &config,
+                &mut context,
+            )
+            .await;
+        }
+        assert_eq!(notifier.titles().len(), 1);
+        assert!(state_manager.get_thread_dispatched_at("1").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cooled_down_thread_still_posts_and_marks_read() {
+        let webhook = crate::test_support::HttpRecorder::start().await;
+        let mut config: Config = toml::from_str("[notification]\ndedupe_window_sec = 300").unwrap();
+        config.notification.dnd = DndMode::Off;
+        config.mark_as_read_on_notify = true;
+        config.webhook.enabled = true;
+        config.webhook.url = Some(webhook.url.clone());
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
 
-    loop {
-        interval.tick().await; // 次のポーリングまで待機
+        let notification = create_test_notification("1", "user/repo1");
+        let mut contexts = [PollingContext::new(), PollingContext::new()];
+        for context in &mut contexts {
+            dispatch_notifications(
+                &[&notification],
+                &notifier,
+                &mut github_client,
+                &mut state_manager,
+                &config,
+                context,
+            )
+            .await;
+        }
 
-        // StateManager から最終確認日時を取得
-        let if_modified_since = state_manager.get_last_checked_at();
+        // The second update is within the window: no popup, but it is still posted and marked read
+        assert_eq!(notifier.titles().len(), 1);
+        assert_eq!(webhook.bodies().len(), 2);
+        assert_eq!(contexts[1].pending_reads.thread_ids(), vec!["1"]);
+    }
 
-        // GitHub API から通知を取得
-        match github_client
-            .get_notifications(if_modified_since, None)
-            .await
-        {
-            Ok(Some(notifications)) => {
-                // 最終確認日時以降の新しい通知のみを処理
-                let new_notifications = crate::polling::filter::filter_new_notifications(
-                    &notifications,
-                    state_manager,
-                    config,
-                );
+    #[tokio::test]
+    async fn test_dnd_suppresses_popups() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.notification.dnd = DndMode::On;
+        config.mark_as_read_on_notify = true;
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
 
-                if !new_notifications.is_empty() {
-                    // 最新の通知の updated_at を最終確認日時として更新
-                    if let Some(latest) = new_notifications.iter().max_by_key(|n| &n.updated_at) {
-                        state_manager.update_last_checked_at(latest.updated_at.clone());
-                    }
+        let notifications = [create_test_notification("1", "user/repo1")];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+        let delivered = dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
 
-                    // バッチ処理が有効な場合はバッファに追加
-                    if batch_size > 0 {
-                        for notification in new_notifications {
-                            batch_buffer.push_back(notification.clone());
-                        }
-
-                        // バッチサイズに達したか、時間経過時に処理
-                        if batch_buffer.len() >= batch_size
-                            || last_batch_time.elapsed() >= batch_interval
-                        {
-                            if let Err(e) = process_batch(
-                                &batch_buffer,
-                                notifier,
-                                github_client,
-                                config,
-                                error_handling,
-                            )
-                            .await
-                            {
-                                tracing::error!("Failed to process batch: {}", e);
-                            }
-                            batch_buffer.clear();
-                            last_batch_time = Instant::now();
-                        }
-                    } else {
-                        // バッチ処理が無効な場合は1つずつ処理
-                        for notification in new_notifications {
-                            // 通知を Notifier に渡す
-                            if let Err(e) = crate::polling::handler::handle_notification(
-                                notification,
-                                notifier,
-                                github_client,
-                                config.mark_as_read_on_notify,
-                            )
-                            .await
-                            {
-                                tracing::error!("Failed to handle notification: {}", e);
-                            }
-                        }
-                    }
+        // Only the popup is suppressed: still marked read and counted as delivered
+        assert!(notifier.titles().is_empty());
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(context.pending_reads.thread_ids(), vec!["1"]);
+    }
 
-                    // 状態を保存
-                    if let Err(e) = state_manager.save() {
-                        tracing::error!("Failed to save state: {}", e);
-                    }
-                }
-            }
-            Ok(None) => {
-                // 304 Not Modified
-                tracing::debug!("No new notifications (304 Not Modified)");
-            }
-            Err(e) => {
-                tracing::error!("Error fetching notifications: {}", e);
-            }
-        }
+    #[tokio::test]
+    async fn test_quiet_hours_suppress_only_popups() {
+        let webhook = crate::test_support::HttpRecorder::start().await;
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.mark_as_read_on_notify = true;
+        config.webhook.enabled = true;
+        config.webhook.url = Some(webhook.url.clone());
+        // Quiet all day, every day
+        config.notification.quiet_days = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"]
+            .iter()
+            .map(|day| day.to_string())
+            .collect();
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let mut state_manager =
+            StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let notification = create_test_notification("1", "user/repo1");
+        process_new_notifications(
+            &[&notification],
+            &config,
+            &mut github_client,
+            &mut state_manager,
+            &notifier,
+            &mut context,
+        )
+        .await;
+
+        // No popup, but the webhook, mark-read and the counters still run
+        assert!(notifier.titles().is_empty());
+        assert_eq!(webhook.bodies().len(), 1);
+        assert_eq!(context.pending_reads.thread_ids(), vec!["1"]);
+        assert_eq!(state_manager.state.counters.total, 1);
     }
-}
 
-/// シャットダウンシグナル付きでポーリングを実行する非同期ループ
-pub async fn run_polling_loop_with_shutdown(
-    config: &Config,
-    github_client: &mut GitHubClient,
-    state_manager: &mut StateManager,
-    notifier: &dyn Notifier,
-    shutdown_rx: &mut broadcast::Receiver<()>,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let mut interval = interval(StdDuration::from_secs(config.poll_interval_sec));
-    // バッチ処理用のバッファとタイマー
-    let batch_size = config.notification_batch_config.batch_size;
-    let batch_interval =
-        StdDuration::from_secs(config.notification_batch_config.batch_interval_sec);
-    let error_handling = &config.polling_error_handling_config;
-    let mut batch_buffer: VecDeque<Notification> = VecDeque::new();
-    let mut last_batch_time = Instant::now();
+    #[tokio::test]
+    async fn test_counters_record_only_delivered_notifications() {
+        let mut config = create_test_config(NotificationMode::Off);
+        config.notification_filters.auto_read_reasons = vec!["subscribed".to_string()];
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let mut state_manager =
+            StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
 
-    loop {
-        // シャットダウンシグナルを待機しつつ、ポーリング間隔を待機
-        tokio::select! {
-            _ = interval.tick() => {
-                // StateManager から最終確認日時を取得
-                let if_modified_since = state_manager.get_last_checked_at();
+        // Not shown in Off mode: not counted
+        let suppressed = create_test_notification("1", "user/repo1");
+        process_new_notifications(
+            &[&suppressed],
+            &config,
+            &mut github_client,
+            &mut state_manager,
+            &notifier,
+            &mut context,
+        )
+        .await;
+        assert!(notifier.titles().is_empty());
+        assert_eq!(state_manager.state.counters.total, 0);
 
-                // GitHub API から通知を取得
-                match github_client
-                    .get_notifications(if_modified_since, None)
-                    .await
-                {
-                    Ok(Some(notifications)) => {
-                        // 最終確認日時以降の新しい通知のみを処理
-                        let new_notifications = crate::polling::filter::filter_new_notifications(
-                            &notifications,
-                            state_manager,
-                            config,
-                        );
+        // Only the popup is counted, not the auto-read notification
+        config.notification.mode = NotificationMode::Individual;
+        let mut subscribed = create_test_notification("2", "user/repo1");
+        subscribed.reason = "subscribed".to_string();
+        let delivered = create_test_notification("3", "user/repo2");
+        process_new_notifications(
+            &[&subscribed, &delivered],
+            &config,
+            &mut github_client,
+            &mut state_manager,
+            &notifier,
+            &mut context,
+        )
+        .await;
+        assert_eq!(notifier.titles().len(), 1);
+        let counters = &state_manager.state.counters;
+        assert_eq!(counters.total, 1);
+        assert_eq!(counters.by_repository.get("user/repo2"), Some(&1));
+        assert!(!counters.by_reason.contains_key("subscribed"));
+    }
 
-                        if !new_notifications.is_empty() {
-                            // 最新の通知の updated_at を最終確認日時として更新
-                            if let Some(latest) = new_notifications.iter().max_by_key(|n| &n.updated_at) {
-                                state_manager.update_last_checked_at(latest.updated_at.clone());
-                            }
-
-                            // バッチ処理が有効な場合はバッファに追加
-                            if batch_size > 0 {
-                                for notification in new_notifications {
-                                    batch_buffer.push_back(notification.clone());
-                                }
-
-                                // バッチサイズに達したか、時間経過時に処理
-                                if batch_buffer.len() >= batch_size
-                                    || last_batch_time.elapsed() >= batch_interval
-                                {
-                                    if let Err(e) = process_batch(
-                                        &batch_buffer,
-                                        notifier,
-                                        github_client,
-                                        config,
-                                        error_handling,
-                                    )
-                                    .await
-                                    {
-                                        tracing::error!("Failed to process batch: {}", e);
-                                    }
-                                    batch_buffer.clear();
-                                    last_batch_time = Instant::now();
-                                }
-                            } else {
-                                // バッチ処理が無効な場合は1つずつ処理
-                                for notification in new_notifications {
-                                    // 通知を Notifier に渡す
-                                    if let Err(e) = crate::polling::handler::handle_notification(
-                                        notification,
-                                        notifier,
-                                        github_client,
-                                        config.mark_as_read_on_notify,
-                                    )
-                                    .await
-                                    {
-                                        tracing::error!("Failed to handle notification: {}", e);
-                                    }
-                                }
-                            }
-
-                            // 状態を保存
-                            if let Err(e) = state_manager.save() {
-                                tracing::error!("Failed to save state: {}", e);
-                            }
-                        }
-                    }
-                    Ok(None) => {
-                        // 304 Not Modified
-                        tracing::debug!("No new notifications (304 Not Modified)");
-                    }
-                    Err(e) => {
-                        tracing::error!("Error fetching notifications: {}", e);
-                    }
-                }
-            }
-            _ = shutdown_rx.recv() => {
-                tracing::info!("Shutdown signal received, saving state and exiting...");
-                // 終了前に状態を保存
-                if let Err(e) = state_manager.save() {
-                    tracing::error!("Failed to save state on shutdown: {}", e);
-                }
-                tracing::info!("State saved, exiting polling loop");
-                return Ok(());
-            }
+    #[tokio::test]
+    async fn test_outbound_posts_do_not_depend_on_desktop_mode() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let webhook = crate::test_support::HttpRecorder::start().await;
+        let slack = crate::test_support::HttpRecorder::start().await;
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let notifications = [create_test_notification("1", "user/repo1")];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+        for mode in [NotificationMode::Off, NotificationMode::Digest] {
+            let mut config = create_test_config(mode);
+            config.webhook.enabled = true;
+            config.webhook.url = Some(webhook.url.clone());
+            config.notification.slack.enabled = true;
+            config.notification.slack.webhook_url = Some(slack.url.clone());
+
+            dispatch_notifications(
+                &new_notifications,
+                &notifier,
+                &mut github_client,
+                &mut state_manager,
+                &config,
+                &mut context,
+            )
+            .await;
         }
+
+        // No popup, but both modes still post to the webhook and Slack
+        assert!(notifier.titles().is_empty());
+        assert_eq!(webhook.bodies().len(), 2);
+        assert!(webhook.bodies()[0].contains("\"id\":\"1\""));
+        assert_eq!(slack.bodies().len(), 2);
     }
-}
 
-/// バッチ処理を実行
-async fn process_batch(
-    batch: &VecDeque<Notification>,
-    notifier: &dyn Notifier,
-    github_client: &mut GitHubClient,
-    config: &Config,
-    _error_handling: &crate::config::PollingErrorHandlingConfig,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    for notification in batch {
-        // 通知を Notifier に渡す
-        if let Err(e) = crate::polling::handler::handle_notification(
-            notification,
-            notifier,
-            github_client,
-            config.mark_as_read_on_notify,
+    #[tokio::test]
+    async fn test_failed_popups_still_post_and_mark_read() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let webhook = crate::test_support::HttpRecorder::start().await;
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.mark_as_read_on_notify = true;
+        config.webhook.enabled = true;
+        config.webhook.url = Some(webhook.url.clone());
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let mut context = PollingContext::new();
+
+        let notifications = [
+            create_test_notification("1", "user/repo1"),
+            create_test_notification("2", "user/repo2"),
+        ];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+        let delivered = dispatch_notifications(
+            &new_notifications,
+            &FailingNotifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
         )
-        .await
-        {
-            tracing::error!("Failed to handle notification: {}", e);
-        }
+        .await;
+
+        // The popups failed, but the webhook and mark-read still ran for every notification
+        assert!(delivered.is_empty());
+        assert_eq!(webhook.bodies().len(), 2);
+        assert_eq!(context.pending_reads.thread_ids(), vec!["1", "2"]);
     }
-    Ok(())
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{AuthManager, Config, GitHubClient, StateManager};
-    use tokio::sync::broadcast;
+    #[tokio::test]
+    async fn test_auto_read_reasons_skip_popup() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.notification_filters.auto_read_reasons = vec!["subscribed".to_string()];
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
 
-    struct MockNotifier;
+        let mut subscribed = create_test_notification("1", "user/repo1");
+        subscribed.reason = "subscribed".to_string();
+        let notifications = [subscribed, create_test_notification("2", "user/repo2")];
+        assert!(crate::polling::filter::is_auto_read(
+            &notifications[0],
+            &config
+        ));
+
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
+
+        // Only the mention pops up
+        let titles = notifier.titles();
+        assert_eq!(titles.len(), 1);
+        assert!(titles[0].starts_with("user/repo2"));
+
+        // Auto-read notifications are not counted as unread in the digest
+        context.digest.update(&notifications, &config);
+        assert_eq!(context.digest.unread_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_drain_batch_on_shutdown() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.notification_batch_config.batch_size = 10;
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let notifications = [
+            create_test_notification("1", "user/repo1"),
+            create_test_notification("2", "user/repo2"),
+        ];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+
+        // Buffered until the batch is full
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
+        assert!(notifier.titles().is_empty());
+        assert_eq!(context.batch_buffer.len(), 2);
+
+        // Shutdown flushes the pending batch
+        drain_batch_on_shutdown(
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
+        assert_eq!(notifier.titles().len(), 2);
+        assert!(context.batch_buffer.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_grouped_by_repository() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.notification_batch_config.batch_size = 3;
+        config.notification.group_by = crate::config::GroupBy::Repository;
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let notifications = [
+            create_test_notification("1", "user/repo1"),
+            create_test_notification("2", "user/repo2"),
+            create_test_notification("3", "user/repo1"),
+        ];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
+
+        // repo1 の2件は1件にまとめられ、repo2 は通常の通知として表示される
+        let titles = notifier.titles();
+        assert_eq!(titles.len(), 2);
+        assert_eq!(titles[0], "user/repo1 - 2 notifications");
+        assert!(titles[1].starts_with("user/repo2"));
+    }
+
+    #[tokio::test]
+    async fn test_batch_group_by_repository_flag() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.notification_batch_config.batch_size = 3;
+        config.notification_batch_config.group_by_repository = true;
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let notifications = [
+            create_test_notification("1", "user/repo1"),
+            create_test_notification("2", "user/repo1"),
+            create_test_notification("3", "user/repo2"),
+        ];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut context,
+        )
+        .await;
+
+        // 1件だけのリポジトリは通常どおり表示される
+        let titles = notifier.titles();
+        assert_eq!(titles.len(), 2);
+        assert_eq!(titles[0], "user/repo1 - 2 notifications");
+        assert!(titles[1].starts_with("user/repo2 - "));
+    }
+
+    #[tokio::test]
+    async fn test_batch_dispatch_follows_sort_order() {
+        let (_state_file, mut state_manager) = create_test_state_manager();
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.notification_batch_config.batch_size = 4;
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+
+        let notification = |id: &str, reason: &str, updated_at: &str| Notification {
+            reason: reason.to_string(),
+            updated_at: updated_at.to_string(),
+            ..create_test_notification(id, &format!("user/repo{}", id))
+        };
+        let notifications = [
+            notification("1", "mention", "2023-01-02T00:00:00Z"),
+            notification("2", "subscribed", "2023-01-04T00:00:00Z"),
+            notification("3", "review_requested", "2023-01-01T00:00:00Z"),
+            notification("4", "comment", "2023-01-03T00:00:00Z"),
+        ];
+        let new_notifications: Vec<&Notification> = notifications.iter().collect();
+
+        let dispatched_repositories = |notifier: &RecordingNotifier| {
+            notifier
+                .titles()
+                .iter()
+                .map(|title| title.split(" - ").next().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // 優先度の低い通知から送信し、最も重要な通知を最後（一覧の一番上）にする
+        let notifier = RecordingNotifier::new();
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut PollingContext::new(),
+        )
+        .await;
+        assert_eq!(
+            dispatched_repositories(&notifier),
+            vec!["user/repo2", "user/repo4", "user/repo3", "user/repo1"]
+        );
+
+        config.notification_batch_config.sort_order = BatchSortOrder::TimeDesc;
+        let notifier = RecordingNotifier::new();
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut PollingContext::new(),
+        )
+        .await;
+        assert_eq!(
+            dispatched_repositories(&notifier),
+            vec!["user/repo3", "user/repo1", "user/repo4", "user/repo2"]
+        );
+
+        config.notification_batch_config.sort_order = BatchSortOrder::Arrival;
+        let notifier = RecordingNotifier::new();
+        dispatch_notifications(
+            &new_notifications,
+            &notifier,
+            &mut github_client,
+            &mut state_manager,
+            &config,
+            &mut PollingContext::new(),
+        )
+        .await;
+        assert_eq!(
+            dispatched_repositories(&notifier),
+            vec!["user/repo1", "user/repo2", "user/repo3", "user/repo4"]
+        );
+    }
+
+    #[test]
+    fn test_announce_lifecycle() {
+        let mut config = create_test_config(NotificationMode::Individual);
+        let notifier = RecordingNotifier::new();
+
+        // デフォルトでは無効
+        announce_lifecycle(&notifier, &config, LifecycleEvent::Started);
+        assert!(notifier.titles().is_empty());
+
+        config.notification.announce_lifecycle = true;
+        announce_lifecycle(&notifier, &config, LifecycleEvent::Started);
+        announce_lifecycle(&notifier, &config, LifecycleEvent::Stopped);
+        assert_eq!(
+            notifier.titles(),
+            vec!["gh-notifier started", "gh-notifier stopped"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_label_filter_keeps_notifications_when_labels_cannot_be_fetched() {
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.notification_filters.include_labels = vec!["bug".to_string()];
+        // トークンがないためラベルの取得は失敗する
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let mut context = PollingContext::new();
+
+        let with_subject = create_test_notification("1", "user/repo1");
+        let mut without_subject = create_test_notification("2", "user/repo1");
+        without_subject.subject.url = None;
+        let notifications = [&with_subject, &without_subject];
+
+        let kept =
+            apply_label_filters(&notifications, &config, &mut github_client, &mut context).await;
+
+        // 取得に失敗した通知は除外せず、判定できなかった件数として記録する
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].id, "1");
+        assert_eq!(
+            context.filter_stats.unverified_breakdown(),
+            vec![("label", 1)]
+        );
+        // ラベルを持たないサブジェクトは include_labels を満たさない
+        assert_eq!(context.filter_stats.breakdown(), vec![("label", 1)]);
+    }
+
+    #[test]
+    fn test_in_quiet_hours() {
+        let mut config = create_test_config(NotificationMode::Individual);
+        let at =
+            |value: &str| chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap();
+        assert!(!in_quiet_hours(&config, at("2026-10-15 02:00")));
+
+        config.notification.quiet_hours = Some("22:00-07:00".to_string());
+        assert!(in_quiet_hours(&config, at("2026-10-15 02:00")));
+        assert!(!in_quiet_hours(&config, at("2026-10-15 09:00")));
+    }
+
+    #[test]
+    fn test_within_active_hours_pauses_and_resumes() {
+        let mut config = create_test_config(NotificationMode::Individual);
+        let mut context = PollingContext::new();
+        let at =
+            |value: &str| chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap();
+
+        // 設定がない場合は常にポーリングする
+        assert!(within_active_hours(
+            &config,
+            &mut context,
+            at("2026-10-14 03:00")
+        ));
+
+        config.polling.active_hours = Some("09:00-18:00".to_string());
+        assert!(!within_active_hours(
+            &config,
+            &mut context,
+            at("2026-10-14 08:00")
+        ));
+        assert!(context.paused_outside_active_hours);
+        assert!(!within_active_hours(
+            &config,
+            &mut context,
+            at("2026-10-14 08:30")
+        ));
+
+        assert!(within_active_hours(
+            &config,
+            &mut context,
+            at("2026-10-14 09:00")
+        ));
+        assert!(!context.paused_outside_active_hours);
+    }
+
+    #[test]
+    fn test_filtered_summary() {
+        let mut config = create_test_config(NotificationMode::Individual);
+        config.notification_filters.exclude_reasons = vec!["subscribed".to_string()];
+        config.notification_filters.exclude_repositories = vec!["user/noisy".to_string()];
+        config.filtered_summary.enabled = true;
+        config.filtered_summary.interval_sec = 0;
+        config.filtered_summary.desktop_notification = true;
+
+        let mut subscribed = create_test_notification("1", "user/repo1");
+        subscribed.reason = "subscribed".to_string();
+        let notifications = [
+            subscribed.clone(),
+            subscribed,
+            create_test_notification("2", "user/noisy"),
+            create_test_notification("3", "user/repo1"),
+        ];
+
+        let mut state_manager = StateManager::new().unwrap();
+        state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
+        let notifier = RecordingNotifier::new();
+        let mut context = PollingContext::new();
+
+        let new_notifications = crate::polling::filter::filter_new_notifications_with_stats(
+            &notifications,
+            &state_manager,
+            &config,
+            &mut context.filter_stats,
+        );
+        assert_eq!(new_notifications.len(), 1);
+        assert_eq!(
+            context.filter_stats.breakdown(),
+            vec![("subscribed", 2), ("excluded-repo", 1)]
+        );
+
+        emit_filtered_summary_if_due(&notifier, &config, &mut context);
+        assert_eq!(notifier.titles().len(), 1);
+        assert_eq!(context.filter_stats.total(), 0);
+
+        // 除外された通知がない場合は何も出力しない
+        emit_filtered_summary_if_due(&notifier, &config, &mut context);
+        assert_eq!(notifier.titles().len(), 1);
+    }
 
     impl crate::poller::Notifier for MockNotifier {
         fn send_notification(
@@ -287,6 +1964,46 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_startup_delay() {
+        let mut polling = crate::config::PollingConfig::default();
+        assert!(startup_delay(&polling).is_zero());
+
+        polling.startup_delay_sec = 2;
+        polling.startup_jitter_sec = 1;
+        for _ in 0..10 {
+            let delay = startup_delay(&polling);
+            assert!(delay >= StdDuration::from_secs(2) && delay <= StdDuration::from_secs(3));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_during_startup_delay() {
+        let mut config = Config::default();
+        config.polling.startup_delay_sec = 3600;
+        let mut github_client = GitHubClient::new(AuthManager::new().unwrap()).unwrap();
+        let mut state_manager = StateManager::new().unwrap();
+        let notifier = MockNotifier;
+
+        let (shutdown_tx, _) = broadcast::channel(1);
+        let mut shutdown_rx = shutdown_tx.subscribe();
+        let _ = shutdown_tx.send(());
+
+        // Exits right away instead of waiting for the delay
+        let result = tokio::time::timeout(
+            StdDuration::from_secs(5),
+            run_polling_loop_with_shutdown(
+                &config,
+                &mut github_client,
+                &mut state_manager,
+                &notifier,
+                &mut shutdown_rx,
+            ),
+        )
+        .await;
+        assert!(matches!(result, Ok(Ok(()))));
+    }
+
     #[tokio::test]
     async fn test_run_polling_loop_with_shutdown_after_creation() {
         let config = Config::default();
@@ -323,4 +2040,31 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_reload_change_detection() {
+        let current = Config::default();
+        let reloaded = Config {
+            notification_filters: crate::config::NotificationFilter {
+                exclude_reasons: vec!["subscribed".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        // フィルタの変更は再起動なしで反映される
+        assert!(restart_required_changes(&current, &reloaded).is_empty());
+        assert!(!polling_schedule_changed(&current, &reloaded));
+
+        let reloaded = Config {
+            pat: Some("ghp_new".to_string()),
+            log_level: "debug".to_string(),
+            poll_interval_sec: 120,
+            ..Default::default()
+        };
+        assert_eq!(
+            restart_required_changes(&current, &reloaded),
+            vec!["pat", "log_level"]
+        );
+        assert!(polling_schedule_changed(&current, &reloaded));
+    }
 }
@@ -0,0 +1,275 @@
+use crate::Notification;
+use crate::config::RepositoryOverride;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// ポーリングの取得元
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PollSource {
+    /// 全体の通知一覧（`/notifications`）
+    Inbox,
+    /// 個別のリポジトリ（`/repos/{owner}/{repo}/notifications`）
+    Repository(String),
+}
+
+/// 全体の通知一覧と `repository_overrides` のリポジトリを取得するタイミングを管理
+pub struct PollSchedule {
+    inbox_interval: Duration,
+    next_inbox: Instant,
+    repositories: Vec<ScheduledRepository>,
+}
+
+struct ScheduledRepository {
+    full_name: String,
+    interval: Duration,
+    next_due: Instant,
+    /// このリポジトリで最後に処理した通知の `updated_at`
+    last_checked_at: Option<String>,
+}
+
+impl PollSchedule {
+    /// `start` の時点で全体の通知一覧を取得し、各リポジトリはそれぞれの間隔の後から取得する
+    ///
+    /// 全体のポーリング間隔以上の間隔が指定されたリポジトリは全体の取得で足りるため無視する
+    pub fn new(poll_interval_sec: u64, overrides: &[RepositoryOverride], start: Instant) -> Self {
+        let inbox_interval = Duration::from_secs(poll_interval_sec);
+        let mut repositories: Vec<ScheduledRepository> = Vec::new();
+        for repository_override in overrides {
+            let interval = Duration::from_secs(repository_override.poll_interval_sec);
+            if interval.is_zero() || interval >= inbox_interval {
+                tracing::warn!(
+                    "Ignoring repository override for {}: poll_interval_sec must be shorter than poll_interval_sec ({}s)",
+                    repository_override.repository,
+                    poll_interval_sec
+                );
+                continue;
+            }
+            if repositories
+                .iter()
+                .any(|r| r.full_name == repository_override.repository)
+            {
+                continue;
+            }
+            repositories.push(ScheduledRepository {
+                full_name: repository_override.repository.clone(),
+                interval,
+                next_due: start + interval,
+                last_checked_at: None,
+            });
+        }
+
+        PollSchedule {
+            inbox_interval,
+            next_inbox: start,
+            repositories,
+        }
+    }
+
+    /// タイマーの間隔（すべての間隔の最大公約数）
+    pub fn tick_interval(&self) -> Duration {
+        let secs = self
+            .repositories
+            .iter()
+            .fold(self.inbox_interval.as_secs(), |acc, r| {
+                gcd(acc, r.interval.as_secs())
+            });
+        Duration::from_secs(secs.max(1))
+    }
+
+    /// `now` の時点で取得すべき取得元を返し、次の取得時刻を進める
+    pub fn due(&mut self, now: Instant) -> Vec<PollSource> {
+        let mut sources = Vec::new();
+        if now >= self.next_inbox {
+            sources.push(PollSource::Inbox);
+            self.next_inbox = now + self.inbox_interval;
+        }
+        for repository in &mut self.repositories {
+            if now >= repository.next_due {
+                sources.push(PollSource::Repository(repository.full_name.clone()));
+                repository.next_due = now + repository.interval;
+            }
+        }
+        sources
+    }
+
+    /// すべての取得元の次の取得時刻を `until` 以降に遅らせる
+    pub fn defer_until(&mut self, until: Instant) {
+        self.next_inbox = self.next_inbox.max(until);
+        for repository in &mut self.repositories {
+            repository.next_due = repository.next_due.max(until);
+        }
+    }
+
+    /// リポジトリで最後に処理した通知の `updated_at`
+    pub fn repository_last_checked_at(&self, full_name: &str) -> Option<&str> {
+        self.repositories
+            .iter()
+            .find(|r| r.full_name == full_name)
+            .and_then(|r| r.last_checked_at.as_deref())
+    }
+
+    pub fn set_repository_last_checked_at(&mut self, full_name: &str, updated_at: String) {
+        if let Some(repository) = self
+            .repositories
+            .iter_mut()
+            .find(|r| r.full_name == full_name)
+        {
+            repository.last_checked_at = Some(updated_at);
+        }
+    }
+}
+
+/// リポジトリごとの取得で処理済みの通知（全体の通知一覧で重複して通知しないために使用）
+#[derive(Debug, Default)]
+pub struct SeenNotifications {
+    updated_at_by_id: HashMap<String, String>,
+}
+
+impl SeenNotifications {
+    pub fn mark(&mut self, notification: &Notification) {
+        self.updated_at_by_id
+            .insert(notification.id.clone(), notification.updated_at.clone());
+    }
+
+    /// 同じ更新（`updated_at`）をすでに処理しているかどうか
+    pub fn contains(&self, notification: &Notification) -> bool {
+        self.updated_at_by_id
+            .get(&notification.id)
+            .is_some_and(|updated_at| *updated_at == notification.updated_at)
+    }
+
+    /// 全体の最終確認日時以前の通知は全体の取得でも除外されるため削除
+    pub fn prune_through(&mut self, last_checked_at: &str) {
+        self.updated_at_by_id
+            .retain(|_, updated_at| updated_at.as_str() > last_checked_at);
+    }
+
+    pub fn len(&self) -> usize {
+        self.updated_at_by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.updated_at_by_id.is_empty()
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repository_override(repository: &str, poll_interval_sec: u64) -> RepositoryOverride {
+        RepositoryOverride {
+            repository: repository.to_string(),
+            poll_interval_sec,
+        }
+    }
+
+    fn create_test_notification(id: &str, updated_at: &str) -> Notification {
+        Notification {
+            updated_at: updated_at.to_string(),
+            ..crate::test_support::notification(id, "org/api")
+        }
+    }
+
+    #[test]
+    fn test_schedule_due_sources() {
+        let start = Instant::now();
+        let mut schedule = PollSchedule::new(
+            60,
+            &[
+                repository_override("org/api", 20),
+                repository_override("org/web", 30),
+                // 全体の間隔以上のものは無視
+                repository_override("org/docs", 120),
+            ],
+            start,
+        );
+        assert_eq!(schedule.tick_interval(), Duration::from_secs(10));
+
+        let at = |secs| start + Duration::from_secs(secs);
+        let repository = |name: &str| PollSource::Repository(name.to_string());
+
+        assert_eq!(schedule.due(at(0)), vec![PollSource::Inbox]);
+        assert!(schedule.due(at(10)).is_empty());
+        assert_eq!(schedule.due(at(20)), vec![repository("org/api")]);
+        assert_eq!(schedule.due(at(30)), vec![repository("org/web")]);
+        assert_eq!(schedule.due(at(40)), vec![repository("org/api")]);
+        assert!(schedule.due(at(50)).is_empty());
+        assert_eq!(
+            schedule.due(at(60)),
+            vec![
+                PollSource::Inbox,
+                repository("org/api"),
+                repository("org/web")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schedule_without_overrides_polls_inbox_every_tick() {
+        let start = Instant::now();
+        let mut schedule = PollSchedule::new(30, &[], start);
+        assert_eq!(schedule.tick_interval(), Duration::from_secs(30));
+        for tick in 0..3 {
+            assert_eq!(
+                schedule.due(start + Duration::from_secs(30 * tick)),
+                vec![PollSource::Inbox]
+            );
+        }
+    }
+
+    #[test]
+    fn test_schedule_defer_until() {
+        let start = Instant::now();
+        let mut schedule = PollSchedule::new(60, &[repository_override("org/api", 20)], start);
+        let at = |secs| start + Duration::from_secs(secs);
+
+        assert_eq!(schedule.due(at(0)), vec![PollSource::Inbox]);
+        schedule.defer_until(at(300));
+        assert!(schedule.due(at(60)).is_empty());
+        assert_eq!(
+            schedule.due(at(300)),
+            vec![
+                PollSource::Inbox,
+                PollSource::Repository("org/api".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repository_last_checked_at() {
+        let mut schedule =
+            PollSchedule::new(60, &[repository_override("org/api", 10)], Instant::now());
+        assert_eq!(schedule.repository_last_checked_at("org/api"), None);
+        schedule.set_repository_last_checked_at("org/api", "2023-01-02T00:00:00Z".to_string());
+        assert_eq!(
+            schedule.repository_last_checked_at("org/api"),
+            Some("2023-01-02T00:00:00Z")
+        );
+        // 設定されていないリポジトリは無視
+        schedule.set_repository_last_checked_at("org/other", "2023-01-02T00:00:00Z".to_string());
+        assert_eq!(schedule.repository_last_checked_at("org/other"), None);
+    }
+
+    #[test]
+    fn test_seen_notifications_dedup() {
+        let mut seen = SeenNotifications::default();
+        let first = create_test_notification("1", "2023-01-02T00:00:00Z");
+        seen.mark(&first);
+
+        // 同じ更新は重複として除外し、新しい更新は通知する
+        assert!(seen.contains(&first));
+        assert!(!seen.contains(&create_test_notification("1", "2023-01-03T00:00:00Z")));
+        assert!(!seen.contains(&create_test_notification("2", "2023-01-02T00:00:00Z")));
+
+        seen.mark(&create_test_notification("3", "2023-01-04T00:00:00Z"));
+        seen.prune_through("2023-01-02T00:00:00Z");
+        assert_eq!(seen.len(), 1);
+        assert!(!seen.contains(&first));
+    }
+}
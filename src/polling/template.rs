@@ -0,0 +1,95 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// テンプレートで使用できるプレースホルダー
+pub const PLACEHOLDERS: [&str; 6] = ["repo", "reason", "title", "type", "age", "url"];
+
+/// `{name}` 形式のプレースホルダー
+static PLACEHOLDER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{(\w+)\}").expect("valid placeholder pattern"));
+
+/// テンプレートに展開する通知の値
+#[derive(Debug, Clone, Default)]
+pub struct TemplateValues<'a> {
+    pub repo: &'a str,
+    pub reason: &'a str,
+    pub title: &'a str,
+    pub kind: &'a str,
+    pub age: &'a str,
+    pub url: &'a str,
+}
+
+impl TemplateValues<'_> {
+    fn get(&self, name: &str) -> Option<&str> {
+        match name {
+            "repo" => Some(self.repo),
+            "reason" => Some(self.reason),
+            "title" => Some(self.title),
+            "type" => Some(self.kind),
+            "age" => Some(self.age),
+            "url" => Some(self.url),
+            _ => None,
+        }
+    }
+}
+
+/// テンプレートのプレースホルダーを通知の値で置き換える（未知のプレースホルダーはそのまま残す）
+pub fn render(template: &str, values: &TemplateValues) -> String {
+    PLACEHOLDER
+        .replace_all(template, |caps: &regex::Captures| {
+            values.get(&caps[1]).unwrap_or(&caps[0]).to_string()
+        })
+        .into_owned()
+}
+
+/// テンプレートに未知のプレースホルダーが含まれていないか検証
+pub fn validate(template: &str) -> Result<(), String> {
+    for caps in PLACEHOLDER.captures_iter(template) {
+        if !PLACEHOLDERS.contains(&&caps[1]) {
+            return Err(format!(
+                "unknown placeholder '{}' (available: {})",
+                &caps[0],
+                PLACEHOLDERS.map(|name| format!("{{{}}}", name)).join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> TemplateValues<'static> {
+        TemplateValues {
+            repo: "user/repo",
+            reason: "mentioned you",
+            title: "Fix the build",
+            kind: "Pull Request",
+            age: "5m ago",
+            url: "https://github.com/user/repo/pull/1",
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("{repo} — {reason}: {title}", &values()),
+            "user/repo — mentioned you: Fix the build"
+        );
+        assert_eq!(
+            render("{type} ({age})\n{url}", &values()),
+            "Pull Request (5m ago)\nhttps://github.com/user/repo/pull/1"
+        );
+        // 未知のプレースホルダーや閉じていない括弧はそのまま
+        assert_eq!(render("{unknown} {title", &values()), "{unknown} {title");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("{repo} {reason} {title} {type} {age} {url}").is_ok());
+        assert!(validate("no placeholders").is_ok());
+        let error = validate("{repo}: {author}").unwrap_err();
+        assert!(error.contains("{author}"));
+    }
+}
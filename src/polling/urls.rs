@@ -0,0 +1,262 @@
+use crate::Notification;
+use crate::config::web_base_url_for;
+
+/// 通知一覧のページ（ブラウザで開く URL を解決できなかった場合にも使用する）
+///
+/// `web_base` は `GitHubConfig::web_base_url` の Web のページのベース URL
+pub fn notifications_page_url(web_base: &str) -> String {
+    format!("{}/notifications", web_base)
+}
+
+/// 通知を開くときに使用する URL を決定
+///
+/// `resolve_open_url` で解決できない場合は GitHub の通知一覧のページを使用する
+pub fn open_url(notification: &Notification, web_base: &str) -> String {
+    resolve_open_url(notification, web_base).unwrap_or_else(|| {
+        tracing::warn!(
+            id = %notification.id,
+            "Could not resolve a URL for the notification, using the notifications page"
+        );
+        notifications_page_url(web_base)
+    })
+}
+
+/// 通知をブラウザで開くための URL を次の順に解決する
+///
+/// 1. サブジェクトの URL（API の URL の場合は対応する Web のページに変換）
+/// 2. リポジトリのページ
+pub fn resolve_open_url(notification: &Notification, web_base: &str) -> Option<String> {
+    if let Some(url) = subject_html_url(notification) {
+        tracing::debug!(id = %notification.id, url = %url, "Resolved URL from the subject");
+        return Some(url);
+    }
+
+    let full_name = &notification.repository.full_name;
+    if is_valid_full_name(full_name) {
+        tracing::debug!(
+            id = %notification.id,
+            "Subject URL unavailable, falling back to the repository URL"
+        );
+        return Some(repository_url(web_base, full_name));
+    }
+
+    None
+}
+
+/// サブジェクトの URL から Web のページの URL を導出（ホストはサブジェクトの API の URL から求める）
+fn subject_html_url(notification: &Notification) -> Option<String> {
+    let url = notification.subject.url.as_deref()?;
+    if url.starts_with("https://github.com/") {
+        return Some(url.to_string());
+    }
+
+    let (api_base, path) = url.split_once("/repos/")?;
+    let web_base = web_base_url_for(api_base)?;
+    let mut segments = path.splitn(4, '/');
+    let owner = segments.next()?;
+    let repo = segments.next()?;
+    let kind = segments.next()?;
+    let reference = segments.next()?.trim_end_matches('/');
+    if owner.is_empty() || repo.is_empty() {
+        return None;
+    }
+
+    match kind {
+        "compare" | "commits" => compare_url(notification),
+        "issues" if is_number(reference) => Some(format!(
+            "{}/{}/{}/issues/{}",
+            web_base, owner, repo, reference
+        )),
+        "pulls" if is_number(reference) => Some(format!(
+            "{}/{}/{}/pull/{}",
+            web_base, owner, repo, reference
+        )),
+        "discussions" if is_number(reference) => Some(format!(
+            "{}/{}/{}/discussions/{}",
+            web_base, owner, repo, reference
+        )),
+        // API のリリース ID からはタグ名が分からないためリリース一覧を開く
+        "releases" => Some(format!("{}/{}/{}/releases", web_base, owner, repo)),
+        _ => None,
+    }
+}
+
+/// サブジェクトの API URL からコミット範囲（compare）または単一コミットの URL を導出
+///
+/// - `.../repos/{owner}/{repo}/compare/{base}...{head}` → `<Web のベース URL>/{owner}/{repo}/compare/{base}...{head}`
+/// - `.../repos/{owner}/{repo}/commits/{sha}` → `<Web のベース URL>/{owner}/{repo}/commit/{sha}`
+pub fn compare_url(notification: &Notification) -> Option<String> {
+    let api_url = notification.subject.url.as_deref()?;
+    let (api_base, path) = api_url.split_once("/repos/")?;
+    let web_base = web_base_url_for(api_base)?;
+    let mut segments = path.splitn(4, '/');
+    let owner = segments.next()?;
+    let repo = segments.next()?;
+    let kind = segments.next()?;
+    let reference = segments.next()?.trim_end_matches('/');
+
+    match kind {
+        "compare" => {
+            let (base, head) = reference.split_once("...")?;
+            if is_sha(base) && is_sha(head) {
+                Some(format!(
+                    "{}/{}/{}/compare/{}...{}",
+                    web_base, owner, repo, base, head
+                ))
+            } else {
+                None
+            }
+        }
+        "commits" if is_sha(reference) => Some(format!(
+            "{}/{}/{}/commit/{}",
+            web_base, owner, repo, reference
+        )),
+        _ => None,
+    }
+}
+
+/// リポジトリのページの URL
+pub fn repository_url(web_base: &str, full_name: &str) -> String {
+    format!("{}/{}", web_base, full_name)
+}
+
+fn is_valid_full_name(full_name: &str) -> bool {
+    full_name
+        .split_once('/')
+        .is_some_and(|(owner, repo)| !owner.is_empty() && !repo.is_empty() && !repo.contains('/'))
+}
+
+fn is_number(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_sha(value: &str) -> bool {
+    (7..=40).contains(&value.len()) && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GITHUB: &str = "https://github.com";
+    const GHE: &str = "https://ghe.example.com";
+
+    fn create_test_notification(kind: &str, subject_url: Option<&str>) -> Notification {
+        let mut notification = crate::test_support::notification("1", "user/repo");
+        notification.subject.kind = kind.to_string();
+        notification.subject.url = subject_url.map(|u| u.to_string());
+        notification
+    }
+
+    #[test]
+    fn test_compare_url_derivation() {
+        let compare = create_test_notification(
+            "Commit",
+            Some("https://api.github.com/repos/user/repo/compare/1a2b3c4...5d6e7f8"),
+        );
+        assert_eq!(
+            open_url(&compare, GITHUB),
+            "https://github.com/user/repo/compare/1a2b3c4...5d6e7f8"
+        );
+
+        let commit = create_test_notification(
+            "Commit",
+            Some(
+                "https://api.github.com/repos/user/repo/commits/0123456789abcdef0123456789abcdef01234567",
+            ),
+        );
+        assert_eq!(
+            open_url(&commit, GITHUB),
+            "https://github.com/user/repo/commit/0123456789abcdef0123456789abcdef01234567"
+        );
+
+        // SHA が取得できない場合はリポジトリの URL にフォールバック
+        let missing = create_test_notification("Commit", None);
+        assert_eq!(open_url(&missing, GITHUB), "https://github.com/user/repo");
+        let invalid = create_test_notification(
+            "Commit",
+            Some("https://api.github.com/repos/user/repo/compare/main...feature"),
+        );
+        assert_eq!(open_url(&invalid, GITHUB), "https://github.com/user/repo");
+
+        // API の URL は github.com のページに変換
+        let issue = create_test_notification(
+            "Issue",
+            Some("https://api.github.com/repos/user/repo/issues/1"),
+        );
+        assert_eq!(
+            open_url(&issue, GITHUB),
+            "https://github.com/user/repo/issues/1"
+        );
+        let pull = create_test_notification(
+            "PullRequest",
+            Some("https://api.github.com/repos/user/repo/pulls/2"),
+        );
+        assert_eq!(
+            open_url(&pull, GITHUB),
+            "https://github.com/user/repo/pull/2"
+        );
+    }
+
+    #[test]
+    fn test_open_url_fallback_chain() {
+        // Only the repository is available
+        let repository_only = create_test_notification("CheckSuite", None);
+        assert_eq!(
+            resolve_open_url(&repository_only, GITHUB).as_deref(),
+            Some("https://github.com/user/repo")
+        );
+
+        // Malformed subject URL falls back to the repository
+        let malformed = create_test_notification("Issue", Some("not a url"));
+        assert_eq!(open_url(&malformed, GITHUB), "https://github.com/user/repo");
+
+        // Nothing usable: resolution fails and open_url uses the notifications page
+        let mut nothing = create_test_notification("Issue", None);
+        nothing.repository.full_name = String::new();
+        assert!(resolve_open_url(&nothing, GITHUB).is_none());
+        assert_eq!(
+            open_url(&nothing, GITHUB),
+            "https://github.com/notifications"
+        );
+    }
+
+    #[test]
+    fn test_enterprise_server_urls() {
+        let issue = create_test_notification(
+            "Issue",
+            Some("https://ghe.example.com/api/v3/repos/user/repo/issues/7"),
+        );
+        assert_eq!(
+            open_url(&issue, GHE),
+            "https://ghe.example.com/user/repo/issues/7"
+        );
+
+        let commit = create_test_notification(
+            "Commit",
+            Some("https://ghe.example.com/api/v3/repos/user/repo/commits/1a2b3c4"),
+        );
+        assert_eq!(
+            open_url(&commit, GHE),
+            "https://ghe.example.com/user/repo/commit/1a2b3c4"
+        );
+    }
+
+    #[test]
+    fn test_enterprise_server_fallback_urls() {
+        // サブジェクトの URL がない場合のリポジトリのページ
+        let repository_only = create_test_notification("Issue", None);
+        assert_eq!(
+            open_url(&repository_only, GHE),
+            "https://ghe.example.com/user/repo"
+        );
+
+        // 何も解決できない場合の通知一覧のページ
+        let mut nothing = create_test_notification("Issue", None);
+        nothing.repository.full_name = String::new();
+        assert_eq!(
+            open_url(&nothing, GHE),
+            "https://ghe.example.com/notifications"
+        );
+    }
+}
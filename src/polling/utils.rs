@@ -1,4 +1,6 @@
-use chrono::DateTime;
+use chrono::{DateTime, FixedOffset};
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
 
 /// Extracts organization name from full repository name (e.g. "org/repo" -> "org")
 pub fn extract_org_name(full_repo_name: &str) -> String {
@@ -10,11 +12,33 @@ pub fn extract_org_name(full_repo_name: &str) -> String {
 }
 
 /// Parses ISO 8601 format date string to Unix timestamp
+/// パターンの文字が候補の中に順番通りに現れるかどうか（大文字小文字は区別しない）
+///
+/// 例: `webbackend` は `myorg/web-backend-service` に一致する
+pub fn fuzzy_matches(pattern: &str, candidate: &str) -> bool {
+    SkimMatcherV2::default()
+        .ignore_case()
+        .fuzzy_match(candidate, pattern)
+        .is_some()
+}
+
+/// 候補がリストのいずれかに一致するかどうか（`fuzzy` が true の場合はあいまい一致）
+pub fn matches_any(patterns: &[String], candidate: &str, fuzzy: bool) -> bool {
+    if fuzzy {
+        patterns.iter().any(|p| fuzzy_matches(p, candidate))
+    } else {
+        patterns.iter().any(|p| p == candidate)
+    }
+}
+
 pub fn parse_iso8601(date_str: &str) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
     let dt = DateTime::parse_from_rfc3339(date_str)?;
     Ok(dt.timestamp() as u64)
 }
 
+/// Units accepted by `parse_duration`, for error messages
+pub const DURATION_UNITS: &str = "ms, s, m, h (or hr), d, mo (30 days) or yr (365 days)";
+
 /// Parses duration string (e.g. "1h", "30m", "2d") to Duration
 pub fn parse_duration(
     duration_str: &str,
@@ -34,29 +58,21 @@ pub fn parse_duration(
         let first_part = &duration_str[..duration_str.len() - 2];
 
         match last_two {
-            "ms" => {
-                if !first_part.is_empty() {
-                    let num = first_part.parse::<u64>()?;
-                    return Ok(std::time::Duration::from_millis(num));
-                }
+            "ms" if !first_part.is_empty() => {
+                let num = first_part.parse::<u64>()?;
+                return Ok(std::time::Duration::from_millis(num));
             }
-            "hr" => {
-                if !first_part.is_empty() {
-                    let num = first_part.parse::<u64>()?;
-                    return Ok(std::time::Duration::from_secs(num * 60 * 60));
-                }
+            "hr" if !first_part.is_empty() => {
+                let num = first_part.parse::<u64>()?;
+                return Ok(std::time::Duration::from_secs(num * 60 * 60));
             }
-            "mo" => {
-                if !first_part.is_empty() {
-                    let num = first_part.parse::<u64>()?;
-                    return Ok(std::time::Duration::from_secs(num * 60 * 60 * 24 * 30)); // 月を30日として計算
-                }
+            "mo" if !first_part.is_empty() => {
+                let num = first_part.parse::<u64>()?;
+                return Ok(std::time::Duration::from_secs(num * 60 * 60 * 24 * 30)); // 月を30日として計算
             }
-            "yr" => {
-                if !first_part.is_empty() {
-                    let num = first_part.parse::<u64>()?;
-                    return Ok(std::time::Duration::from_secs(num * 60 * 60 * 24 * 365)); // 年を365日として計算
-                }
+            "yr" if !first_part.is_empty() => {
+                let num = first_part.parse::<u64>()?;
+                return Ok(std::time::Duration::from_secs(num * 60 * 60 * 24 * 365)); // 年を365日として計算
             }
             _ => {
                 // Not a two-character unit, continue to check one-character units
@@ -70,29 +86,21 @@ pub fn parse_duration(
         let first_part = &duration_str[..duration_str.len() - 1];
 
         match last_char {
-            "s" => {
-                if !first_part.is_empty() {
-                    let num = first_part.parse::<u64>()?;
-                    return Ok(std::time::Duration::from_secs(num));
-                }
+            "s" if !first_part.is_empty() => {
+                let num = first_part.parse::<u64>()?;
+                return Ok(std::time::Duration::from_secs(num));
             }
-            "m" => {
-                if !first_part.is_empty() {
-                    let num = first_part.parse::<u64>()?;
-                    return Ok(std::time::Duration::from_secs(num * 60));
-                }
+            "m" if !first_part.is_empty() => {
+                let num = first_part.parse::<u64>()?;
+                return Ok(std::time::Duration::from_secs(num * 60));
             }
-            "h" => {
-                if !first_part.is_empty() {
-                    let num = first_part.parse::<u64>()?;
-                    return Ok(std::time::Duration::from_secs(num * 60 * 60));
-                }
+            "h" if !first_part.is_empty() => {
+                let num = first_part.parse::<u64>()?;
+                return Ok(std::time::Duration::from_secs(num * 60 * 60));
             }
-            "d" => {
-                if !first_part.is_empty() {
-                    let num = first_part.parse::<u64>()?;
-                    return Ok(std::time::Duration::from_secs(num * 60 * 60 * 24));
-                }
+            "d" if !first_part.is_empty() => {
+                let num = first_part.parse::<u64>()?;
+                return Ok(std::time::Duration::from_secs(num * 60 * 60 * 24));
             }
             _ => {
                 // Not a recognized unit
@@ -102,3 +110,34 @@ pub fn parse_duration(
 
     Err("Invalid duration format".into())
 }
+
+/// Parses a display timezone ("local", "utc" or a fixed offset like "+09:00")
+///
+/// Returns `None` for the system local timezone.
+pub fn parse_timezone(
+    timezone: &str,
+) -> Result<Option<FixedOffset>, Box<dyn std::error::Error + Send + Sync>> {
+    let timezone = timezone.trim();
+    if timezone.is_empty() || timezone.eq_ignore_ascii_case("local") {
+        return Ok(None);
+    }
+    if timezone.eq_ignore_ascii_case("utc") || timezone == "Z" {
+        return Ok(FixedOffset::east_opt(0));
+    }
+
+    let (sign, rest) = match timezone.chars().next() {
+        Some('+') => (1, &timezone[1..]),
+        Some('-') => (-1, &timezone[1..]),
+        _ => return Err("Timezone must be 'local', 'utc' or an offset like '+09:00'".into()),
+    };
+    let (hours, minutes) = rest.split_once(':').unwrap_or((rest, "0"));
+    let hours = hours.parse::<i32>()?;
+    let minutes = minutes.parse::<i32>()?;
+    if hours > 23 || minutes > 59 {
+        return Err("Timezone offset out of range".into());
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+        .map(Some)
+        .ok_or_else(|| "Timezone offset out of range".into())
+}
@@ -0,0 +1,131 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// GitHub から受信した通知の生の JSON を通知 ID ごとのファイルとして保存する
+///
+/// `debug.store_raw_payload` が有効な場合のみ使用され、保存件数は
+/// `debug.raw_payload_max_entries` を上限として古いものから削除される
+#[derive(Debug, Clone)]
+pub struct RawPayloadStore {
+    dir: PathBuf,
+    max_entries: usize,
+}
+
+impl RawPayloadStore {
+    pub fn new(dir: PathBuf, max_entries: usize) -> Self {
+        RawPayloadStore { dir, max_entries }
+    }
+
+    /// 現在のプロファイルのデータディレクトリ下の `raw_payloads/` を使用
+    pub fn with_default_dir(max_entries: usize) -> Self {
+        Self::new(default_dir(), max_entries)
+    }
+
+    /// 通知の生の JSON を保存
+    pub fn save(
+        &self,
+        id: &str,
+        payload: &serde_json::Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let path = self.payload_path(id)?;
+        fs::create_dir_all(&self.dir)?;
+        fs::write(path, serde_json::to_string_pretty(payload)?)?;
+        Ok(())
+    }
+
+    /// 保存されている生の JSON を取得（存在しない場合は `None`）
+    pub fn load(
+        &self,
+        id: &str,
+    ) -> Result<Option<serde_json::Value>, Box<dyn std::error::Error + Send + Sync>> {
+        let path = self.payload_path(id)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// 上限を超えた古いファイルを削除
+    pub fn prune(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if !self.dir.exists() {
+            return Ok(());
+        }
+
+        let mut entries: Vec<(std::time::SystemTime, PathBuf)> = fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| {
+                let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+                Some((modified, path))
+            })
+            .collect();
+
+        if entries.len() <= self.max_entries {
+            return Ok(());
+        }
+
+        // 新しい順に並べ、上限を超えた分を削除
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.0));
+        for (_, path) in entries.into_iter().skip(self.max_entries) {
+            if let Err(e) = fs::remove_file(&path) {
+                tracing::warn!("Failed to remove raw payload {}: {}", path.display(), e);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 通知 ID からファイルパスを生成（パスとして安全な ID のみ許可）
+    fn payload_path(&self, id: &str) -> Result<PathBuf, Box<dyn std::error::Error + Send + Sync>> {
+        if id.is_empty()
+            || !id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(format!("Invalid notification id: {}", id).into());
+        }
+        Ok(self.dir.join(format!("{}.json", id)))
+    }
+}
+
+/// 生の JSON を保存するデフォルトのディレクトリ
+pub fn default_dir() -> PathBuf {
+    crate::paths::app_dir().join("raw_payloads")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_load_and_prune() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = RawPayloadStore::new(dir.path().join("raw"), 2);
+
+        assert!(store.load("1").unwrap().is_none());
+
+        for id in ["1", "2", "3"] {
+            store
+                .save(id, &serde_json::json!({ "id": id, "extra": [1, 2] }))
+                .unwrap();
+            // mtime の解像度が粗いファイルシステムでも順序が決まるように待機
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+
+        assert_eq!(store.load("3").unwrap().unwrap()["extra"][1], 2);
+
+        store.prune().unwrap();
+        assert!(store.load("1").unwrap().is_none());
+        assert!(store.load("2").unwrap().is_some());
+        assert!(store.load("3").unwrap().is_some());
+
+        // パストラバーサルを防ぐ
+        assert!(store.load("../config").is_err());
+    }
+}
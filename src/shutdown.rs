@@ -24,3 +24,43 @@ pub async fn wait_for_shutdown_signal() {
         _ = terminate => {},
     }
 }
+
+/// SIGHUP（設定の再読み込みなどの手動の更新の合図）の受信（unix 以外では受信しない）
+pub struct HangupSignal {
+    #[cfg(unix)]
+    signal: Option<signal::unix::Signal>,
+}
+
+impl HangupSignal {
+    /// SIGHUP の受信を開始（`enabled` が false の場合は受信せず、SIGHUP のデフォルトの動作のまま）
+    pub fn new(enabled: bool) -> Self {
+        #[cfg(unix)]
+        {
+            let signal = enabled
+                .then(|| signal::unix::signal(signal::unix::SignalKind::hangup()))
+                .and_then(|result| {
+                    result
+                        .inspect_err(|e| tracing::warn!("Failed to install SIGHUP handler: {}", e))
+                        .ok()
+                });
+            HangupSignal { signal }
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = enabled;
+            HangupSignal {}
+        }
+    }
+
+    /// 次の SIGHUP まで待機（受信しない場合は完了しない）
+    pub async fn recv(&mut self) {
+        #[cfg(unix)]
+        if let Some(signal) = &mut self.signal {
+            signal.recv().await;
+            return;
+        }
+
+        std::future::pending::<()>().await
+    }
+}
@@ -0,0 +1,157 @@
+//! 新しい通知を Slack の Incoming Webhook に投稿する（`[notification.slack]`）
+
+use crate::config::{GitHubConfig, SlackConfig};
+use crate::{AuthError, Notification};
+use std::time::Duration;
+
+/// Slack への送信のタイムアウト（ポーリングを長時間止めないため）
+const SLACK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Slack への送信に使用する HTTP クライアントを作成（`[github]` の TLS 設定を反映）
+pub fn build_client(github_config: &GitHubConfig) -> Result<reqwest::Client, AuthError> {
+    crate::github_client::http_client_builder(github_config)?
+        .timeout(SLACK_TIMEOUT)
+        .build()
+        .map_err(|e| AuthError::GeneralError(format!("Failed to create Slack client: {}", e)))
+}
+
+/// Slack の mrkdwn で特別な意味を持つ文字をエスケープ
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// 通知を Slack のメッセージ（タイトル、リポジトリ、理由、リンクボタン）に変換
+///
+/// `web_base` はリンクの解決に使用する Web のページのベース URL
+pub fn payload(notification: &Notification, web_base: &str) -> serde_json::Value {
+    let url = crate::polling::urls::open_url(notification, web_base);
+    let repository = if notification.repository.private {
+        format!("🔒 {}", notification.repository.full_name)
+    } else {
+        notification.repository.full_name.clone()
+    };
+
+    serde_json::json!({
+        // ブロックを表示できないクライアント（通知のプレビューなど）用の本文
+        "text": format!("{}: {}", notification.repository.full_name, notification.subject.title),
+        "blocks": [
+            {
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": format!("*{}*", escape(&notification.subject.title)),
+                },
+            },
+            {
+                "type": "context",
+                "elements": [{
+                    "type": "mrkdwn",
+                    "text": format!(
+                        "{} | {} | {}",
+                        escape(&repository),
+                        escape(&notification.subject.kind),
+                        escape(&notification.reason)
+                    ),
+                }],
+            },
+            {
+                "type": "actions",
+                "elements": [{
+                    "type": "button",
+                    "text": { "type": "plain_text", "text": "Open on GitHub" },
+                    "url": url,
+                }],
+            },
+        ],
+    })
+}
+
+/// 通知を Slack に投稿（無効な場合は何もしない）
+///
+/// 失敗してもポーリングは継続するため、エラーはログに出力するのみ
+pub async fn post_notification(
+    client: &reqwest::Client,
+    config: &SlackConfig,
+    web_base: &str,
+    notification: &Notification,
+) {
+    let (true, Some(url)) = (config.enabled, config.webhook_url.as_deref()) else {
+        return;
+    };
+
+    match client
+        .post(url)
+        .json(&payload(notification, web_base))
+        .send()
+        .await
+    {
+        Ok(response) if response.status().is_success() => {
+            tracing::debug!(id = %notification.id, "Posted notification to Slack");
+        }
+        Ok(response) => tracing::warn!(
+            "Slack returned {} for notification {}",
+            response.status(),
+            notification.id
+        ),
+        Err(e) => tracing::warn!(
+            "Failed to post notification {} to Slack: {}",
+            notification.id,
+            e
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NotificationRepository, NotificationSubject};
+
+    #[test]
+    fn test_payload() {
+        let notification = Notification {
+            id: "1".to_string(),
+            unread: true,
+            reason: "review_requested".to_string(),
+            updated_at: "2023-01-01T00:00:00Z".to_string(),
+            last_read_at: None,
+            subject: NotificationSubject {
+                title: "Fix <script> & cleanup".to_string(),
+                url: Some("https://api.github.com/repos/user/repo/pulls/42".to_string()),
+                latest_comment_url: None,
+                kind: "PullRequest".to_string(),
+            },
+            repository: NotificationRepository {
+                id: 1,
+                node_id: "node1".to_string(),
+                name: "repo".to_string(),
+                full_name: "user/repo".to_string(),
+                private: true,
+                fork: false,
+            },
+            url: "https://api.github.com/notifications/threads/1".to_string(),
+            subscription_url: "https://api.github.com/notifications/threads/1/subscription"
+                .to_string(),
+        };
+
+        let payload = payload(&notification, "https://github.com");
+        assert_eq!(payload["text"], "user/repo: Fix <script> & cleanup");
+
+        let blocks = payload["blocks"].as_array().unwrap();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(
+            blocks[0]["text"]["text"],
+            "*Fix &lt;script&gt; &amp; cleanup*"
+        );
+        assert_eq!(
+            blocks[1]["elements"][0]["text"],
+            "🔒 user/repo | PullRequest | review_requested"
+        );
+        assert_eq!(blocks[2]["elements"][0]["type"], "button");
+        assert_eq!(
+            blocks[2]["elements"][0]["url"],
+            "https://github.com/user/repo/pull/42"
+        );
+    }
+}
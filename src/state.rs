@@ -1,5 +1,6 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::PathBuf;
 
@@ -9,11 +10,139 @@ pub struct State {
     pub last_checked_at: Option<String>,
     /// ETagのマップ（URL -> ETag）
     pub etags: HashMap<String, String>,
+    /// 通知の累計件数（再起動後も保持）
+    #[serde(default)]
+    pub counters: NotificationCounters,
+    /// 最後に取得した通知一覧の未読件数
+    #[serde(default)]
+    pub unread: UnreadCounts,
+    /// スレッドごとの最終デスクトップ通知日時（スレッドID -> ISO 8601形式）
+    #[serde(default)]
+    pub thread_dispatched_at: HashMap<String, String>,
+    /// GitHub で既読にできず再試行を待っている通知
+    #[serde(default)]
+    pub pending_reads: crate::polling::read_queue::ReadQueue,
+}
+
+/// 送信した通知の累計件数（理由ごと・リポジトリごと）
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct NotificationCounters {
+    /// 集計を開始した日時（ISO 8601形式）
+    #[serde(default)]
+    pub since: Option<String>,
+    /// 通知の累計件数
+    #[serde(default)]
+    pub total: u64,
+    /// 通知の理由ごとの累計件数
+    #[serde(default)]
+    pub by_reason: BTreeMap<String, u64>,
+    /// リポジトリごとの累計件数
+    #[serde(default)]
+    pub by_repository: BTreeMap<String, u64>,
+    /// 通知の種類（Issue, PullRequest など）ごとの累計件数
+    #[serde(default)]
+    pub by_subject_type: BTreeMap<String, u64>,
+}
+
+/// 最後に取得した GitHub の通知一覧に含まれる未読の通知の件数（理由ごと・リポジトリごと）
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct UnreadCounts {
+    /// 通知一覧を取得した日時（ISO 8601形式）
+    #[serde(default)]
+    pub as_of: Option<String>,
+    /// 未読の通知の件数
+    #[serde(default)]
+    pub total: u64,
+    /// 通知の理由ごとの未読件数
+    #[serde(default)]
+    pub by_reason: BTreeMap<String, u64>,
+    /// リポジトリごとの未読件数
+    #[serde(default)]
+    pub by_repository: BTreeMap<String, u64>,
+    /// 通知の種類（Issue, PullRequest など）ごとの未読件数
+    #[serde(default)]
+    pub by_subject_type: BTreeMap<String, u64>,
+}
+
+impl UnreadCounts {
+    /// 通知一覧のうち未読の通知を集計する
+    pub fn from_notifications(notifications: &[crate::Notification], now: DateTime<Utc>) -> Self {
+        let mut counts = UnreadCounts {
+            as_of: Some(now.to_rfc3339()),
+            ..Default::default()
+        };
+        for notification in notifications.iter().filter(|n| n.unread) {
+            counts.total += 1;
+            *counts
+                .by_reason
+                .entry(notification.reason.clone())
+                .or_insert(0) += 1;
+            *counts
+                .by_repository
+                .entry(notification.repository.full_name.clone())
+                .or_insert(0) += 1;
+            *counts
+                .by_subject_type
+                .entry(notification.subject.kind.clone())
+                .or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// 取得日時を除いて件数が同じかどうか
+    fn same_counts(&self, other: &UnreadCounts) -> bool {
+        self.total == other.total
+            && self.by_reason == other.by_reason
+            && self.by_repository == other.by_repository
+            && self.by_subject_type == other.by_subject_type
+    }
+}
+
+impl NotificationCounters {
+    /// 通知1件を集計に加える
+    pub fn record(&mut self, notification: &crate::Notification, now: DateTime<Utc>) {
+        if self.since.is_none() {
+            self.since = Some(now.to_rfc3339());
+        }
+        self.total += 1;
+        *self
+            .by_reason
+            .entry(notification.reason.clone())
+            .or_insert(0) += 1;
+        *self
+            .by_repository
+            .entry(notification.repository.full_name.clone())
+            .or_insert(0) += 1;
+        *self
+            .by_subject_type
+            .entry(notification.subject.kind.clone())
+            .or_insert(0) += 1;
+    }
+
+    /// 集計をリセットする
+    pub fn reset(&mut self, now: DateTime<Utc>) {
+        *self = NotificationCounters {
+            since: Some(now.to_rfc3339()),
+            ..Default::default()
+        };
+    }
+
+    /// 集計の開始が `now` と異なる月かどうか
+    pub fn is_from_previous_month(&self, now: DateTime<Utc>) -> bool {
+        self.since
+            .as_deref()
+            .and_then(|since| DateTime::parse_from_rfc3339(since).ok())
+            .is_some_and(|since| {
+                since.format("%Y-%m").to_string() != now.format("%Y-%m").to_string()
+            })
+    }
 }
 
 pub struct StateManager {
     state_file_path: PathBuf,
     pub state: State,
+    /// true の場合は状態をファイルに保存しない（`--dry-run`）
+    read_only: bool,
 }
 
 impl StateManager {
@@ -41,16 +170,12 @@ impl StateManager {
         Ok(StateManager {
             state_file_path,
             state,
+            read_only: false,
         })
     }
 
     fn default_state_file_path() -> PathBuf {
-        let mut path = dirs::config_dir().unwrap_or_else(|| {
-            std::env::current_dir().expect("現在のディレクトリが取得できません")
-        });
-        path.push("gh-notifier");
-        path.push("state.json");
-        path
+        crate::paths::state_file_path()
     }
 
     pub fn new_with_path(path: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
@@ -76,11 +201,27 @@ impl StateManager {
         Ok(StateManager {
             state_file_path: path,
             state,
+            read_only: false,
         })
     }
 
+    /// 状態をファイルに保存しないようにする（メモリ上の状態は通常通り更新する）
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    /// 状態をファイルに保存しない設定かどうか（`--dry-run`）
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
     /// 状態をファイルに保存
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.read_only {
+            tracing::debug!("State is read-only, skipping save");
+            return Ok(());
+        }
+
         let contents = serde_json::to_string_pretty(&self.state)?;
         fs::write(&self.state_file_path, contents)?;
         Ok(())
@@ -96,6 +237,57 @@ impl StateManager {
         self.state.last_checked_at = Some(timestamp);
     }
 
+    /// 通知を累計件数に加える（`reset_monthly` が true の場合は月が変わった時点でリセット）
+    pub fn record_notifications(
+        &mut self,
+        notifications: &[&crate::Notification],
+        reset_monthly: bool,
+        now: DateTime<Utc>,
+    ) {
+        let counters = &mut self.state.counters;
+        if reset_monthly && counters.is_from_previous_month(now) {
+            counters.reset(now);
+        }
+        for notification in notifications {
+            counters.record(notification, now);
+        }
+    }
+
+    /// 取得した通知一覧から未読件数を更新（取得日時以外の件数が変わった場合は true を返す）
+    pub fn update_unread_counts(
+        &mut self,
+        notifications: &[crate::Notification],
+        now: DateTime<Utc>,
+    ) -> bool {
+        let counts = UnreadCounts::from_notifications(notifications, now);
+        let changed = !counts.same_counts(&self.state.unread);
+        self.state.unread = counts;
+        changed
+    }
+
+    /// スレッドを最後にデスクトップ通知した日時を取得
+    pub fn get_thread_dispatched_at(&self, thread_id: &str) -> Option<DateTime<Utc>> {
+        self.state
+            .thread_dispatched_at
+            .get(thread_id)
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.with_timezone(&Utc))
+    }
+
+    /// スレッドをデスクトップ通知した日時を記録
+    pub fn record_thread_dispatched(&mut self, thread_id: String, at: DateTime<Utc>) {
+        self.state
+            .thread_dispatched_at
+            .insert(thread_id, at.to_rfc3339());
+    }
+
+    /// 指定日時より前に通知したスレッドの記録を削除
+    pub fn prune_thread_dispatched_before(&mut self, before: DateTime<Utc>) {
+        self.state.thread_dispatched_at.retain(|_, at| {
+            DateTime::parse_from_rfc3339(at).is_ok_and(|at| at.with_timezone(&Utc) >= before)
+        });
+    }
+
     /// ETagを取得
     pub fn get_etag(&self, url: &str) -> Option<&str> {
         self.state.etags.get(url).map(|s| s.as_str())
@@ -133,6 +325,7 @@ mod tests {
                 );
                 map
             },
+            ..Default::default()
         };
 
         let serialized = serde_json::to_string(&state).unwrap();
@@ -186,16 +379,35 @@ mod tests {
         let state_manager = StateManager {
             state_file_path: std::path::PathBuf::from("/"),
             state: State::default(),
+            read_only: false,
         };
         let result = state_manager.save();
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_read_only_state_manager_skips_save() {
+        // 書き込めないパスでも保存しないためエラーにならない
+        let mut state_manager = StateManager {
+            state_file_path: std::path::PathBuf::from("/"),
+            state: State::default(),
+            read_only: false,
+        };
+        state_manager.set_read_only(true);
+        state_manager.update_last_checked_at("2023-01-01T00:00:00Z".to_string());
+        assert!(state_manager.save().is_ok());
+        assert_eq!(
+            state_manager.get_last_checked_at(),
+            Some("2023-01-01T00:00:00Z")
+        );
+    }
+
     #[test]
     fn test_state_manager_get_set_last_checked_at() {
         let mut state_manager = StateManager {
             state_file_path: std::path::PathBuf::new(),
             state: State::default(),
+            read_only: false,
         };
         let timestamp = "2023-01-01T00:00:00Z".to_string();
         state_manager.update_last_checked_at(timestamp.clone());
@@ -210,10 +422,116 @@ mod tests {
         let mut state_manager = StateManager {
             state_file_path: std::path::PathBuf::new(),
             state: State::default(),
+            read_only: false,
         };
         let url = "https://api.github.com/notifications".to_string();
         let etag = "etag123".to_string();
         state_manager.update_etag(url.clone(), etag.clone());
         assert_eq!(state_manager.get_etag(&url), Some(etag.as_str()));
     }
+
+    #[test]
+    fn test_counters_increment_and_monthly_reset() {
+        use crate::{Notification, NotificationRepository, NotificationSubject};
+
+        let notification = Notification {
+            id: "1".to_string(),
+            unread: true,
+            reason: "review_requested".to_string(),
+            updated_at: "2023-01-01T00:00:00Z".to_string(),
+            last_read_at: None,
+            subject: NotificationSubject {
+                title: "Test".to_string(),
+                url: None,
+                latest_comment_url: None,
+                kind: "PullRequest".to_string(),
+            },
+            repository: NotificationRepository {
+                id: 1,
+                node_id: "node1".to_string(),
+                name: "repo".to_string(),
+                full_name: "user/repo".to_string(),
+                private: false,
+                fork: false,
+            },
+            url: "https://example.com/1".to_string(),
+            subscription_url: "https://example.com/subscription/1".to_string(),
+        };
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut state_manager =
+            StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        let october = "2026-10-14T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let november = "2026-11-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        state_manager.record_notifications(&[&notification, &notification], true, october);
+        state_manager.save().unwrap();
+
+        // Counters survive a restart
+        let mut state_manager =
+            StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        assert_eq!(state_manager.state.counters.total, 2);
+        assert_eq!(
+            state_manager.state.counters.by_reason["review_requested"],
+            2
+        );
+        assert_eq!(state_manager.state.counters.by_repository["user/repo"], 2);
+        assert_eq!(
+            state_manager.state.counters.by_subject_type["PullRequest"],
+            2
+        );
+
+        // Without monthly reset the counters keep growing
+        state_manager.record_notifications(&[&notification], false, november);
+        assert_eq!(state_manager.state.counters.total, 3);
+
+        // With monthly reset a new month starts from zero
+        state_manager.record_notifications(&[&notification], true, november);
+        assert_eq!(state_manager.state.counters.total, 1);
+        assert_eq!(
+            state_manager.state.counters.by_reason["review_requested"],
+            1
+        );
+    }
+
+    #[test]
+    fn test_update_unread_counts() {
+        let notification =
+            |id: &str, unread: bool, reason: &str, repository: &str| crate::Notification {
+                unread,
+                reason: reason.to_string(),
+                ..crate::test_support::notification(id, repository)
+            };
+        let notifications = vec![
+            notification("1", true, "mention", "user/a"),
+            notification("2", true, "review_requested", "user/a"),
+            notification("3", false, "mention", "user/b"),
+        ];
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut state_manager =
+            StateManager::new_with_path(temp_file.path().to_path_buf()).unwrap();
+        let now = "2026-10-14T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        assert!(state_manager.update_unread_counts(&notifications, now));
+        let unread = &state_manager.state.unread;
+        assert_eq!(unread.as_of.as_deref(), Some("2026-10-14T00:00:00+00:00"));
+        // 既読の通知は数えない
+        assert_eq!(unread.total, 2);
+        assert_eq!(unread.by_reason["mention"], 1);
+        assert_eq!(unread.by_repository["user/a"], 2);
+        assert!(!unread.by_repository.contains_key("user/b"));
+        assert_eq!(unread.by_subject_type["Issue"], 2);
+
+        // 件数が変わらなければ取得日時だけを更新する
+        let later = now + chrono::Duration::minutes(1);
+        assert!(!state_manager.update_unread_counts(&notifications, later));
+        assert_eq!(
+            state_manager.state.unread.as_of.as_deref(),
+            Some("2026-10-14T00:01:00+00:00")
+        );
+
+        assert!(state_manager.update_unread_counts(&notifications[2..], later));
+        assert_eq!(state_manager.state.unread.total, 0);
+    }
 }
@@ -0,0 +1,377 @@
+use crate::state::{NotificationCounters, UnreadCounts};
+use std::collections::BTreeMap;
+
+/// 累計件数を `stats --cumulative` の表示用に整形（`top` を指定した場合は各内訳を件数の多い順に `top` 件まで表示）
+pub fn format_counters(counters: &NotificationCounters, top: Option<usize>) -> Vec<String> {
+    let mut lines = vec![match &counters.since {
+        Some(since) => format!("Cumulative notification counters (since {})", since),
+        None => "Cumulative notification counters".to_string(),
+    }];
+    lines.push(format!("Total: {}", counters.total));
+
+    lines.push("By reason:".to_string());
+    lines.extend(format_breakdown(&counters.by_reason, top));
+    lines.push("By repository:".to_string());
+    lines.extend(format_breakdown(&counters.by_repository, top));
+    lines.push("By subject type:".to_string());
+    lines.extend(format_breakdown(&counters.by_subject_type, top));
+
+    lines
+}
+
+/// 累計件数と未読件数を `stats` の表示用に整形（各内訳を累計件数・未読件数の多い順に並べ、`top` を指定した場合は `top` 件まで表示）
+pub fn format_stats(
+    counters: &NotificationCounters,
+    unread: &UnreadCounts,
+    top: Option<usize>,
+) -> Vec<String> {
+    let mut lines = vec![format!(
+        "Notification breakdown (notified since {}, unread as of {})",
+        counters.since.as_deref().unwrap_or("-"),
+        unread.as_of.as_deref().unwrap_or("-")
+    )];
+    lines.push(format!(
+        "Total: {} notified, {} unread",
+        counters.total, unread.total
+    ));
+
+    lines.push("By reason:".to_string());
+    lines.extend(format_table(&counters.by_reason, &unread.by_reason, top));
+    lines.push("By repository:".to_string());
+    lines.extend(format_table(
+        &counters.by_repository,
+        &unread.by_repository,
+        top,
+    ));
+    lines.push("By subject type:".to_string());
+    lines.extend(format_table(
+        &counters.by_subject_type,
+        &unread.by_subject_type,
+        top,
+    ));
+
+    lines
+}
+
+/// `stats --format json` の出力のスキーマのバージョン（フィールドを削除・変更した場合に更新する。追加のみの場合は据え置く）
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// 累計件数を `stats --cumulative --format json` の出力用に変換
+///
+/// ダッシュボードやスクリプトから利用されるため、フィールド名は互換性を保つ（`top` を指定した場合は各内訳を件数の多い順に `top` 件まで含める）
+pub fn counters_json(
+    counters: &NotificationCounters,
+    last_checked_at: Option<&str>,
+    top: Option<usize>,
+) -> serde_json::Value {
+    let breakdown = |counts: &BTreeMap<String, u64>| -> BTreeMap<String, u64> {
+        top_entries(counts, top)
+            .into_iter()
+            .map(|(name, count)| (name.clone(), *count))
+            .collect()
+    };
+    serde_json::json!({
+        "schema_version": JSON_SCHEMA_VERSION,
+        "since": counters.since,
+        "last_checked_at": last_checked_at,
+        "total": counters.total,
+        "by_reason": breakdown(&counters.by_reason),
+        "by_repository": breakdown(&counters.by_repository),
+        "by_subject_type": breakdown(&counters.by_subject_type),
+    })
+}
+
+/// 累計件数と未読件数を `stats --format json` の出力用に変換（`counters_json` に `unread` を加えたもの）
+pub fn stats_json(
+    counters: &NotificationCounters,
+    unread: &UnreadCounts,
+    last_checked_at: Option<&str>,
+    top: Option<usize>,
+) -> serde_json::Value {
+    let breakdown = |counts: &BTreeMap<String, u64>| -> BTreeMap<String, u64> {
+        top_entries(counts, top)
+            .into_iter()
+            .map(|(name, count)| (name.clone(), *count))
+            .collect()
+    };
+    let mut json = counters_json(counters, last_checked_at, top);
+    json["unread"] = serde_json::json!({
+        "as_of": unread.as_of,
+        "total": unread.total,
+        "by_reason": breakdown(&unread.by_reason),
+        "by_repository": breakdown(&unread.by_repository),
+        "by_subject_type": breakdown(&unread.by_subject_type),
+    });
+    json
+}
+
+/// 件数の多い順（同数の場合は名前順）に並べ、`top` 件までに絞る
+fn top_entries(counts: &BTreeMap<String, u64>, top: Option<usize>) -> Vec<(&String, &u64)> {
+    let mut entries: Vec<(&String, &u64)> = counts.iter().collect();
+    entries.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    if let Some(top) = top {
+        entries.truncate(top);
+    }
+    entries
+}
+
+/// 件数の多い順に並べて整形
+fn format_breakdown(counts: &BTreeMap<String, u64>, top: Option<usize>) -> Vec<String> {
+    if counts.is_empty() {
+        return vec!["  (none)".to_string()];
+    }
+
+    let entries = top_entries(counts, top);
+    let omitted = counts.len() - entries.len();
+
+    let width = entries
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    let mut lines: Vec<String> = entries
+        .into_iter()
+        .map(|(name, count)| format!("  {:<width$}  {}", name, count, width = width))
+        .collect();
+    if omitted > 0 {
+        lines.push(format!("  ... and {} more", omitted));
+    }
+    lines
+}
+
+/// 累計件数と未読件数を1つの表に整形（累計件数、未読件数、名前の順に並べる）
+fn format_table(
+    counts: &BTreeMap<String, u64>,
+    unread: &BTreeMap<String, u64>,
+    top: Option<usize>,
+) -> Vec<String> {
+    let mut rows: Vec<(&String, u64, u64)> = counts
+        .keys()
+        .chain(unread.keys().filter(|name| !counts.contains_key(*name)))
+        .map(|name| {
+            (
+                name,
+                counts.get(name).copied().unwrap_or(0),
+                unread.get(name).copied().unwrap_or(0),
+            )
+        })
+        .collect();
+    if rows.is_empty() {
+        return vec!["  (none)".to_string()];
+    }
+    rows.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| b.2.cmp(&a.2))
+            .then_with(|| a.0.cmp(b.0))
+    });
+    let total_rows = rows.len();
+    if let Some(top) = top {
+        rows.truncate(top);
+    }
+
+    let width = rows
+        .iter()
+        .map(|(name, _, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max("name".len());
+    let mut lines = vec![format!(
+        "  {:<width$}  notified  unread",
+        "name",
+        width = width
+    )];
+    lines.extend(rows.iter().map(|(name, notified, unread)| {
+        format!(
+            "  {:<width$}  {:>8}  {:>6}",
+            name,
+            notified,
+            unread,
+            width = width
+        )
+    }));
+    if total_rows > rows.len() {
+        lines.push(format!("  ... and {} more", total_rows - rows.len()));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_counters() {
+        let counters = NotificationCounters {
+            since: Some("2026-10-01T00:00:00+00:00".to_string()),
+            total: 3,
+            by_reason: BTreeMap::from([
+                ("mention".to_string(), 1),
+                ("review_requested".to_string(), 2),
+            ]),
+            by_repository: BTreeMap::new(),
+            by_subject_type: BTreeMap::from([("PullRequest".to_string(), 3)]),
+        };
+
+        let lines = format_counters(&counters, None);
+        assert_eq!(
+            lines,
+            vec![
+                "Cumulative notification counters (since 2026-10-01T00:00:00+00:00)",
+                "Total: 3",
+                "By reason:",
+                "  review_requested  2",
+                "  mention           1",
+                "By repository:",
+                "  (none)",
+                "By subject type:",
+                "  PullRequest  3",
+            ]
+        );
+
+        // --top で各内訳の行数を絞る
+        let lines = format_counters(&counters, Some(1));
+        assert_eq!(
+            &lines[2..5],
+            ["By reason:", "  review_requested  2", "  ... and 1 more"]
+        );
+    }
+
+    #[test]
+    fn test_counters_json_schema() {
+        let counters = NotificationCounters {
+            since: Some("2026-10-01T00:00:00+00:00".to_string()),
+            total: 2,
+            by_reason: BTreeMap::from([("mention".to_string(), 2)]),
+            by_repository: BTreeMap::from([("user/repo".to_string(), 2)]),
+            by_subject_type: BTreeMap::from([("Issue".to_string(), 2)]),
+        };
+
+        let json = counters_json(&counters, Some("2026-10-02T00:00:00Z"), None);
+        let object = json.as_object().unwrap();
+        let mut keys: Vec<&str> = object.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            vec![
+                "by_reason",
+                "by_repository",
+                "by_subject_type",
+                "last_checked_at",
+                "schema_version",
+                "since",
+                "total"
+            ]
+        );
+        assert_eq!(json["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(json["total"], 2);
+        assert_eq!(json["by_reason"]["mention"], 2);
+        assert_eq!(json["by_repository"]["user/repo"], 2);
+        assert_eq!(json["by_subject_type"]["Issue"], 2);
+
+        let json = counters_json(&counters, None, Some(0));
+        assert!(json["by_reason"].as_object().unwrap().is_empty());
+
+        // 集計前は null
+        let empty = counters_json(&NotificationCounters::default(), None, None);
+        assert!(empty["since"].is_null());
+        assert!(empty["last_checked_at"].is_null());
+        assert_eq!(empty["total"], 0);
+    }
+
+    #[test]
+    fn test_format_stats_with_unread() {
+        let counters = NotificationCounters {
+            since: Some("2026-10-01T00:00:00+00:00".to_string()),
+            total: 3,
+            by_reason: BTreeMap::from([
+                ("mention".to_string(), 1),
+                ("review_requested".to_string(), 2),
+            ]),
+            by_repository: BTreeMap::from([("user/repo".to_string(), 3)]),
+            by_subject_type: BTreeMap::from([("PullRequest".to_string(), 3)]),
+        };
+        let unread = UnreadCounts {
+            as_of: Some("2026-10-14T00:00:00+00:00".to_string()),
+            total: 2,
+            by_reason: BTreeMap::from([("mention".to_string(), 1), ("assign".to_string(), 1)]),
+            by_repository: BTreeMap::from([("user/repo".to_string(), 2)]),
+            by_subject_type: BTreeMap::new(),
+        };
+
+        let lines = format_stats(&counters, &unread, None);
+        assert_eq!(
+            lines,
+            vec![
+                "Notification breakdown (notified since 2026-10-01T00:00:00+00:00, unread as of 2026-10-14T00:00:00+00:00)",
+                "Total: 3 notified, 2 unread",
+                "By reason:",
+                "  name              notified  unread",
+                "  review_requested         2       0",
+                "  mention                  1       1",
+                "  assign                   0       1",
+                "By repository:",
+                "  name       notified  unread",
+                "  user/repo         3       2",
+                "By subject type:",
+                "  name         notified  unread",
+                "  PullRequest         3       0",
+            ]
+        );
+
+        // --top で各内訳の行数を絞る
+        let lines = format_stats(&counters, &unread, Some(1));
+        assert_eq!(
+            &lines[2..6],
+            [
+                "By reason:",
+                "  name              notified  unread",
+                "  review_requested         2       0",
+                "  ... and 2 more"
+            ]
+        );
+
+        // 集計前
+        let lines = format_stats(
+            &NotificationCounters::default(),
+            &UnreadCounts::default(),
+            None,
+        );
+        assert_eq!(
+            &lines[..4],
+            [
+                "Notification breakdown (notified since -, unread as of -)",
+                "Total: 0 notified, 0 unread",
+                "By reason:",
+                "  (none)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stats_json_includes_unread() {
+        let counters = NotificationCounters {
+            total: 1,
+            by_reason: BTreeMap::from([("mention".to_string(), 1)]),
+            ..Default::default()
+        };
+        let unread = UnreadCounts {
+            as_of: Some("2026-10-14T00:00:00+00:00".to_string()),
+            total: 2,
+            by_reason: BTreeMap::from([("mention".to_string(), 1), ("assign".to_string(), 1)]),
+            by_repository: BTreeMap::from([("user/repo".to_string(), 2)]),
+            by_subject_type: BTreeMap::from([("Issue".to_string(), 2)]),
+        };
+
+        let json = stats_json(&counters, &unread, None, None);
+        assert_eq!(json["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(json["total"], 1);
+        assert_eq!(json["unread"]["as_of"], "2026-10-14T00:00:00+00:00");
+        assert_eq!(json["unread"]["total"], 2);
+        assert_eq!(json["unread"]["by_reason"]["assign"], 1);
+        assert_eq!(json["unread"]["by_repository"]["user/repo"], 2);
+        assert_eq!(json["unread"]["by_subject_type"]["Issue"], 2);
+
+        let json = stats_json(&counters, &unread, None, Some(1));
+        assert_eq!(json["unread"]["by_reason"].as_object().unwrap().len(), 1);
+    }
+}
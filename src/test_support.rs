@@ -0,0 +1,104 @@
+//! テストで共通して使用するフィクスチャ
+
+use crate::{Notification, NotificationRepository, NotificationSubject};
+
+/// テスト用の通知（理由は `mention`、種類は `Issue`。その他のフィールドはテストごとに必要に応じて書き換える）
+pub(crate) fn notification(id: &str, full_name: &str) -> Notification {
+    Notification {
+        id: id.to_string(),
+        unread: true,
+        reason: "mention".to_string(),
+        updated_at: "2023-01-02T00:00:00Z".to_string(),
+        last_read_at: None,
+        subject: NotificationSubject {
+            title: format!("Notification {}", id),
+            url: None,
+            latest_comment_url: None,
+            kind: "Issue".to_string(),
+        },
+        repository: NotificationRepository {
+            id: 1,
+            node_id: "node1".to_string(),
+            name: full_name
+                .split('/')
+                .next_back()
+                .unwrap_or_default()
+                .to_string(),
+            full_name: full_name.to_string(),
+            private: false,
+            fork: false,
+        },
+        url: format!("https://api.github.com/notifications/threads/{}", id),
+        subscription_url: format!(
+            "https://api.github.com/notifications/threads/{}/subscription",
+            id
+        ),
+    }
+}
+
+/// 受信したリクエストの本文を記録して 200 を返すテスト用の HTTP サーバー（Webhook・Slack の送信先）
+pub(crate) struct HttpRecorder {
+    /// リクエストの送信先の URL
+    pub url: String,
+    bodies: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl HttpRecorder {
+    pub(crate) async fn start() -> Self {
+        use tokio::io::AsyncWriteExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let bodies = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = bodies.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let recorded = recorded.clone();
+                tokio::spawn(async move {
+                    if let Some(body) = read_request_body(&mut stream).await {
+                        recorded.lock().unwrap().push(body);
+                    }
+                    let _ = stream
+                        .write_all(
+                            b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                        )
+                        .await;
+                });
+            }
+        });
+        HttpRecorder { url, bodies }
+    }
+
+    /// 受信したリクエストの本文（受信順）
+    pub(crate) fn bodies(&self) -> Vec<String> {
+        self.bodies.lock().unwrap().clone()
+    }
+}
+
+/// HTTP リクエストを読み込み、`Content-Length` の長さの本文を返す
+async fn read_request_body(stream: &mut tokio::net::TcpStream) -> Option<String> {
+    use tokio::io::AsyncReadExt;
+
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        if let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            let headers = String::from_utf8_lossy(&buffer[..end]).to_ascii_lowercase();
+            let length = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            let body_start = end + 4;
+            if buffer.len() >= body_start + length {
+                let body = &buffer[body_start..body_start + length];
+                return Some(String::from_utf8_lossy(body).into_owned());
+            }
+        }
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+}
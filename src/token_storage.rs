@@ -13,8 +13,12 @@ pub struct TokenStorage {
 
 impl TokenStorage {
     pub fn new() -> Result<Self, AuthError> {
-        // Try to create keyring entry
-        let keyring_entry = match Entry::new("gh-notifier", "github_auth_token") {
+        // Try to create keyring entry (named profiles use their own keyring account)
+        let keyring_user = match crate::paths::current_profile() {
+            Some(profile) => format!("github_auth_token:{}", profile),
+            None => "github_auth_token".to_string(),
+        };
+        let keyring_entry = match Entry::new("gh-notifier", &keyring_user) {
             Ok(entry) => Some(Arc::new(entry)),
             Err(e) => {
                 tracing::warn!(
@@ -26,10 +30,7 @@ impl TokenStorage {
         };
 
         // Create path for fallback token file
-        let mut token_file_path = dirs::config_dir()
-            .unwrap_or_else(|| std::env::current_dir().expect("Current directory not accessible"));
-        token_file_path.push("gh-notifier");
-        token_file_path.push("token.json");
+        let token_file_path = crate::paths::token_file_path();
 
         // Create directory if it doesn't exist
         if let Some(parent) = token_file_path.parent() {
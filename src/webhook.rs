@@ -0,0 +1,110 @@
+//! 新しい通知を外部の URL に POST する Webhook（`[webhook]`）
+
+use crate::config::{GitHubConfig, WebhookConfig};
+use crate::{AuthError, Notification};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::Duration;
+
+/// 署名を格納するヘッダー（値は `sha256=<16進数>`）
+pub const SIGNATURE_HEADER: &str = "X-GH-Notifier-Signature";
+
+/// Webhook の送信のタイムアウト（ポーリングを長時間止めないため）
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Webhook の送信に使用する HTTP クライアントを作成（`[github]` の TLS 設定を反映）
+pub fn build_client(github_config: &GitHubConfig) -> Result<reqwest::Client, AuthError> {
+    crate::github_client::http_client_builder(github_config)?
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .map_err(|e| AuthError::GeneralError(format!("Failed to create webhook client: {}", e)))
+}
+
+/// 本文の HMAC-SHA256 署名（`sha256=<16進数>`）
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    let hex: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    format!("sha256={}", hex)
+}
+
+/// 通知を JSON として Webhook に POST（無効な場合は何もしない）
+///
+/// 失敗してもポーリングは継続するため、エラーはログに出力するのみ
+pub async fn post_notification(
+    client: &reqwest::Client,
+    config: &WebhookConfig,
+    notification: &Notification,
+) {
+    let (true, Some(url)) = (config.enabled, config.url.as_deref()) else {
+        return;
+    };
+
+    let body = match serde_json::to_vec(notification) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to serialize notification {} for webhook: {}",
+                notification.id,
+                e
+            );
+            return;
+        }
+    };
+
+    let mut request = client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+    if let Some(secret) = config.secret.as_deref() {
+        request = request.header(SIGNATURE_HEADER, sign(secret, &body));
+    }
+
+    match request.body(body).send().await {
+        Ok(response) if response.status().is_success() => {
+            tracing::debug!(id = %notification.id, "Posted notification to webhook");
+        }
+        Ok(response) => tracing::warn!(
+            "Webhook returned {} for notification {}",
+            response.status(),
+            notification.id
+        ),
+        Err(e) => tracing::warn!(
+            "Failed to post notification {} to webhook: {}",
+            notification.id,
+            e
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        // HMAC-SHA256 のよく知られたテストベクタ（key = "key"）
+        assert_eq!(
+            sign("key", b"The quick brown fox jumps over the lazy dog"),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+        assert_ne!(sign("other", b"body"), sign("key", b"body"));
+    }
+
+    #[test]
+    fn test_build_client_uses_github_tls_settings() {
+        assert!(build_client(&GitHubConfig::default()).is_ok());
+
+        // 存在しない CA 証明書は GitHub API と同じくエラーにする
+        let github_config = GitHubConfig {
+            ca_bundle: Some("/nonexistent/ca.pem".to_string()),
+            ..Default::default()
+        };
+        assert!(build_client(&github_config).is_err());
+    }
+}